@@ -1,8 +1,8 @@
 use crate::build::dyn_builder::DynCallPatternBuilder;
-use crate::call_pattern::CallPattern;
+use crate::call_pattern::{CallPattern, PatIndex, Scope};
 use crate::fn_mocker::{FnMocker, PatternMatchMode};
 use crate::output::ResponderError;
-use crate::private::lib::{format, vec, BTreeMap, Entry, String, ToString};
+use crate::private::lib::{format, vec, BTreeMap, Entry, String, ToString, Vec};
 use crate::Clause;
 use crate::{clause, MockFnInfo};
 
@@ -11,6 +11,8 @@ use core::any::TypeId;
 pub(crate) struct MockAssembler {
     fn_mockers: BTreeMap<TypeId, FnMocker>,
     current_call_index: usize,
+    // The parent of each scope, indexed by scope:
+    scope_parents: Vec<Scope>,
 }
 
 impl MockAssembler {
@@ -25,11 +27,12 @@ impl MockAssembler {
         Self {
             fn_mockers: BTreeMap::new(),
             current_call_index: 0,
+            scope_parents: vec![Scope::ROOT],
         }
     }
 
-    pub fn finish(self) -> BTreeMap<TypeId, FnMocker> {
-        self.fn_mockers
+    pub fn finish(self) -> (BTreeMap<TypeId, FnMocker>, Vec<Scope>) {
+        (self.fn_mockers, self.scope_parents)
     }
 }
 
@@ -45,7 +48,13 @@ impl clause::term::Sink for MockAssembler {
             });
         }
 
+        let child_clauses = core::mem::take(&mut builder.child_clauses);
+        if let Some(error) = child_clauses.error {
+            return Err(error);
+        }
+
         let pattern_match_mode = builder.pattern_match_mode;
+        let scope = builder.scope;
         let mock_type_id = info.type_id;
 
         let call_pattern = self.new_call_pattern(builder);
@@ -56,14 +65,28 @@ impl clause::term::Sink for MockAssembler {
                     return Err(
                         format!(
                             "A clause for {path} has already been registered as {old_mode:?}, but got re-registered as {new_mode:?}. They cannot be mixed for the same MockFn.",
-                            path = &entry.get().info.path,
+                            path = entry.get().info.path,
                             old_mode = entry.get().pattern_match_mode,
                             new_mode = pattern_match_mode,
                         ),
                     );
                 }
 
-                entry.get_mut().call_patterns.push(call_pattern);
+                let fn_mocker = entry.get_mut();
+                fn_mocker.call_patterns.push(call_pattern);
+
+                if pattern_match_mode == PatternMatchMode::InAnyOrder {
+                    let pat_index = PatIndex(fn_mocker.call_patterns.len() - 1);
+
+                    if let Some(shadowing_index) = find_shadowing_call_pattern(fn_mocker, pat_index)
+                    {
+                        return Err(format!(
+                            "{pattern} can never match, because it is shadowed by the catch-all {shadowing_pattern} with unbounded call count.",
+                            pattern = fn_mocker.debug_pattern(pat_index),
+                            shadowing_pattern = fn_mocker.debug_pattern(shadowing_index),
+                        ));
+                    }
+                }
             }
             Entry::Vacant(entry) => {
                 entry.insert(FnMocker {
@@ -74,6 +97,16 @@ impl clause::term::Sink for MockAssembler {
             }
         }
 
+        // Child instances share the state, so their patterns are ordered right after the parent pattern:
+        for child_scope in child_clauses.scopes {
+            child_scope.assign(Scope(self.scope_parents.len()));
+            self.scope_parents.push(scope);
+        }
+        for (child_scope, child_info, mut child_builder) in child_clauses.terms {
+            child_builder.scope = child_scope.get();
+            self.push(child_info, child_builder)?;
+        }
+
         Ok(())
     }
 }
@@ -95,6 +128,7 @@ impl MockAssembler {
         }
 
         CallPattern {
+            scope: builder.scope,
             input_matcher: builder.input_matcher,
             responders: builder.responders,
             ordered_call_index_range,
@@ -102,3 +136,20 @@ impl MockAssembler {
         }
     }
 }
+
+/// Find an earlier call pattern that always gets selected before the given pattern.
+///
+/// `InAnyOrder` patterns are tried in definition order, so a catch-all pattern
+/// which can match an unbounded number of calls makes every later pattern of the same instance unreachable.
+fn find_shadowing_call_pattern(fn_mocker: &FnMocker, pat_index: PatIndex) -> Option<PatIndex> {
+    let scope = fn_mocker.call_patterns[pat_index.0].scope;
+
+    fn_mocker.call_patterns[..pat_index.0]
+        .iter()
+        .position(|call_pattern| {
+            call_pattern.scope == scope
+                && call_pattern.input_matcher.catch_all
+                && call_pattern.call_counter.expectation().is_unbounded()
+        })
+        .map(PatIndex)
+}
@@ -3,19 +3,22 @@ use core::marker::PhantomData;
 use crate::call_pattern::*;
 use crate::clause::{self};
 use crate::fn_mocker::PatternMatchMode;
-use crate::output::{IntoCloneResponder, IntoOnceResponder, IntoResponse, Respond, StaticRef};
+use crate::output::{
+    IntoCloneResponder, IntoOnceResponder, IntoResponse, MockPointer, Respond, StaticRef,
+};
 use crate::private::lib::vec;
 use crate::property::*;
 use crate::Clause;
 use crate::*;
 
 pub(crate) mod dyn_builder {
+    use crate::clause::term;
     use crate::output::ResponderError;
-    use crate::private::lib::{vec, Vec};
-    use crate::Responder;
+    use crate::private::lib::{vec, String, Vec};
+    use crate::{Clause, MockFnInfo, Responder};
 
     use crate::{
-        call_pattern::{DynCallOrderResponder, DynInputMatcher, DynResponder},
+        call_pattern::{ChildScope, DynCallOrderResponder, DynInputMatcher, DynResponder, Scope},
         counter,
         fn_mocker::PatternMatchMode,
     };
@@ -28,6 +31,26 @@ pub(crate) mod dyn_builder {
         pub(crate) count_expectation: counter::CallCountExpectation,
         pub(crate) current_response_index: usize,
         pub(crate) responder_error: Option<ResponderError>,
+        pub(crate) child_clauses: ChildClauses,
+        pub(crate) scope: Scope,
+    }
+
+    /// Clauses for child instances created by the responders of a call pattern.
+    ///
+    /// They get registered in the same state as the parent pattern, scoped to the child instance.
+    #[derive(Default)]
+    pub(crate) struct ChildClauses {
+        pub(crate) scopes: Vec<ChildScope>,
+        pub(crate) terms: Vec<(ChildScope, MockFnInfo, DynCallPatternBuilder)>,
+        pub(crate) error: Option<String>,
+    }
+
+    impl term::Sink for ChildClauses {
+        fn push(&mut self, info: MockFnInfo, builder: DynCallPatternBuilder) -> Result<(), String> {
+            let scope = self.scopes.last().expect("BUG: no child scope").clone();
+            self.terms.push((scope, info, builder));
+            Ok(())
+        }
     }
 
     impl DynCallPatternBuilder {
@@ -42,6 +65,8 @@ pub(crate) mod dyn_builder {
                 count_expectation: Default::default(),
                 current_response_index: 0,
                 responder_error: None,
+                child_clauses: Default::default(),
+                scope: Scope::ROOT,
             }
         }
     }
@@ -95,6 +120,16 @@ pub(crate) mod dyn_builder {
             })
         }
 
+        pub fn push_child_clause(&mut self, scope: ChildScope, clause: impl Clause) {
+            let child_clauses = &mut self.inner_mut().child_clauses;
+            child_clauses.scopes.push(scope);
+            if let Err(error) = clause.deconstruct(child_clauses) {
+                if child_clauses.error.is_none() {
+                    child_clauses.error = Some(error);
+                }
+            }
+        }
+
         /// Note: must be called after `push_responder`
         pub fn quantify(&mut self, times: usize, exactness: counter::Exactness) {
             let builder = self.inner_mut();
@@ -344,6 +379,33 @@ macro_rules! define_response_common_impl {
                 self.quantify()
             }
 
+            /// Specify the response of the call pattern to be a child [Unimock] instance, set up with the given clause.
+            ///
+            /// The response type has to be a smart pointer to a trait object of a mocked trait, like `Box<dyn Trait>` or `Arc<dyn Trait>` (see [MockPointer]).
+            /// The trait has to be mocked with `#[unimock(dyn_mock=true)]`.
+            ///
+            /// The child shares its state with the parent instance: The clause is verified when the original instance is torn down,
+            /// and the order of `next_call` patterns is tracked across the parent and all of its children.
+            /// The call patterns of the clause only match calls on the children created by this responder,
+            /// so the parent and other children may mock the same methods differently.
+            /// Calls to methods the clause has no call patterns for fall back to the call patterns of the parent.
+            pub fn returns_mock(mut self, clause: impl Clause) -> Quantify<'p, F, O>
+            where
+                <F::Response as Respond>::Type: MockPointer,
+            {
+                let scope = ChildScope::default();
+                self.wrapper.push_child_clause(scope.clone(), clause);
+                self.wrapper.push_responder(
+                    FunctionResponder::<F> {
+                        func: crate::private::lib::Box::new(move |_, ctx| {
+                            MockPointer::from_unimock(ctx.unimock.child_instance(scope.get()))
+                        }),
+                    }
+                    .into_dyn_responder(),
+                );
+                self.quantify()
+            }
+
             /// Prevent this call pattern from succeeding by explicitly panicking with a custom message.
             pub fn panics(mut self, message: impl Into<String>) -> Quantify<'p, F, O> {
                 let message = message.into();
@@ -1,5 +1,6 @@
-use crate::private::lib::{Box, String, Vec};
+use crate::private::lib::{Arc, Box, String, Vec};
 use core::any::Any;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::build;
 use crate::cell::{Cell, CloneCell, FactoryCell};
@@ -14,6 +15,34 @@ pub(crate) struct PatIndex(pub usize);
 #[derive(Clone, Copy)]
 pub(crate) struct InputIndex(pub usize);
 
+/// The instance whose calls a call pattern matches.
+///
+/// Patterns set up by the clause of [returns_mock](crate::build::DefineResponse::returns_mock)
+/// only match calls on the child instances it creates.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub(crate) struct Scope(pub usize);
+
+impl Scope {
+    pub const ROOT: Self = Self(0);
+}
+
+/// The scope of a child instance, which is unknown until its clause gets assembled into the shared state.
+#[derive(Clone, Default)]
+pub(crate) struct ChildScope(Arc<AtomicUsize>);
+
+impl ChildScope {
+    pub fn assign(&self, scope: Scope) {
+        self.0.store(scope.0, Ordering::SeqCst);
+    }
+
+    pub fn get(&self) -> Scope {
+        match self.0.load(Ordering::SeqCst) {
+            0 => panic!("BUG: Child scope not assigned"),
+            index => Scope(index),
+        }
+    }
+}
+
 impl core::fmt::Display for PatIndex {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "#{}", self.0)
@@ -34,6 +63,7 @@ fn downcast_box<T: 'static>(any_box: &AnyBox) -> PatternResult<&T> {
 }
 
 pub(crate) struct CallPattern {
+    pub scope: Scope,
     pub input_matcher: DynInputMatcher,
     pub responders: Vec<DynCallOrderResponder>,
     pub ordered_call_index_range: core::ops::Range<usize>,
@@ -44,17 +74,16 @@ impl CallPattern {
     pub fn match_inputs<F: MockFn>(
         &self,
         inputs: &F::Inputs<'_>,
-        mismatch_reporter: Option<&mut MismatchReporter>,
+        mutation: &F::Mutation<'_>,
+        mismatch_reporter: &mut MismatchReporter,
     ) -> PatternResult<bool> {
-        match (&self.input_matcher.dyn_matching_fn, mismatch_reporter) {
-            (Some(DynMatchingFn(f)), Some(reporter)) => {
-                Ok((downcast_box::<MatchingFn<F>>(f)?.0)(inputs, reporter))
-            }
-            (Some(DynMatchingFn(f)), None) => Ok((downcast_box::<MatchingFn<F>>(f)?.0)(
+        match &self.input_matcher.dyn_matching_fn {
+            Some(DynMatchingFn(f)) => Ok((downcast_box::<MatchingFn<F>>(f)?.0)(
                 inputs,
-                &mut MismatchReporter::new_disabled(),
+                mutation,
+                mismatch_reporter,
             )),
-            (None, _) => Err(PatternError::NoMatcherFunction),
+            None => Err(PatternError::NoMatcherFunction),
         }
     }
 
@@ -74,6 +103,7 @@ impl CallPattern {
 pub(crate) struct DynInputMatcher {
     dyn_matching_fn: Option<DynMatchingFn>,
     pub(crate) matcher_debug: Option<debug::InputMatcherDebug>,
+    pub(crate) catch_all: bool,
 }
 
 impl DynInputMatcher {
@@ -84,6 +114,7 @@ impl DynInputMatcher {
         Self {
             dyn_matching_fn: builder.matching_fn.map(|f| DynMatchingFn(Box::new(f))),
             matcher_debug: builder.matcher_debug,
+            catch_all: builder.catch_all,
         }
     }
 }
@@ -92,7 +123,11 @@ struct DynMatchingFn(AnyBox);
 
 pub(crate) struct MatchingFn<F: MockFn>(
     #[allow(clippy::type_complexity)]
-    pub  Box<dyn (for<'i> Fn(&F::Inputs<'i>, &mut MismatchReporter) -> bool) + Send + Sync>,
+    pub  Box<
+        dyn (for<'i, 'm> Fn(&F::Inputs<'i>, &F::Mutation<'m>, &mut MismatchReporter) -> bool)
+            + Send
+            + Sync,
+    >,
 );
 
 pub(crate) struct DynCallOrderResponder {
@@ -15,6 +15,10 @@ impl CallCounter {
             .fetch_add(1, core::sync::atomic::Ordering::SeqCst)
     }
 
+    pub fn expectation(&self) -> &CallCountExpectation {
+        &self.expectation
+    }
+
     pub fn verify(
         &self,
         info: &MockFnInfo,
@@ -61,6 +65,10 @@ impl CallCountExpectation {
         }
     }
 
+    pub fn is_unbounded(&self) -> bool {
+        !matches!(self.exactness, Exactness::Exact)
+    }
+
     pub fn exact_calls(&self) -> Option<NCalls> {
         match self.exactness {
             Exactness::Exact => Some(NCalls(self.minimum)),
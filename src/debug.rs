@@ -48,6 +48,32 @@ impl Display for TraitMethodPath {
     }
 }
 
+/// Bit set of input indexes that must not be shown in diagnostics.
+#[derive(Clone, Copy)]
+pub(crate) struct RedactedInputs(u32);
+
+impl RedactedInputs {
+    pub const NONE: Self = Self(0);
+
+    pub const fn new(input_indexes: &[usize]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < input_indexes.len() {
+            assert!(
+                input_indexes[i] < 32,
+                "Only the first 32 inputs can be redacted"
+            );
+            bits |= 1 << input_indexes[i];
+            i += 1;
+        }
+        Self(bits)
+    }
+
+    pub fn contains(&self, input_index: usize) -> bool {
+        input_index < 32 && self.0 & (1 << input_index) != 0
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct CallPatternDebug {
     inner: Box<CallPatternDebugInner>,
@@ -1,6 +1,6 @@
 use crate::build::AnswerContext;
 use crate::call_pattern::{
-    CallPattern, DowncastResponder, DynResponder, PatIndex, PatternError, PatternResult,
+    CallPattern, DowncastResponder, DynResponder, PatIndex, PatternError, PatternResult, Scope,
 };
 use crate::error::{self};
 use crate::error::{MockError, MockResult};
@@ -33,10 +33,14 @@ pub(crate) fn eval<'u, 'i, F: MockFn>(
     let dyn_ctx = DynCtx {
         info: F::info(),
         shared_state: &unimock.shared_state,
+        scope: unimock.scope,
         input_debugger: &|| F::debug_inputs(&inputs),
     };
 
-    match dyn_ctx.eval_dyn(&|pattern, reporter| pattern.match_inputs::<F>(&inputs, reporter))? {
+    let eval_result = dyn_ctx
+        .eval_dyn(&|pattern, reporter| pattern.match_inputs::<F>(&inputs, mutation, reporter))?;
+
+    match eval_result {
         EvalResult::Responder(eval_responder) => match eval_responder.dyn_responder {
             DynResponder::Cell(dyn_cell_responder) => match dyn_ctx
                 .downcast_responder::<F, _>(dyn_cell_responder, &eval_responder)?
@@ -103,6 +107,7 @@ pub(crate) fn eval<'u, 'i, F: MockFn>(
 struct DynCtx<'u, 's> {
     info: MockFnInfo,
     shared_state: &'u SharedState,
+    scope: Scope,
     input_debugger: &'s dyn Fn() -> Vec<Option<String>>,
 }
 
@@ -110,14 +115,27 @@ impl<'u, 's> DynCtx<'u, 's> {
     #[inline(never)]
     fn eval_dyn(
         &self,
-        match_inputs: &dyn Fn(&CallPattern, Option<&mut MismatchReporter>) -> PatternResult<bool>,
+        match_inputs: &dyn Fn(&CallPattern, &mut MismatchReporter) -> PatternResult<bool>,
     ) -> MockResult<EvalResult<'u>> {
-        let fn_mocker = match self.shared_state.fn_mockers.get(&self.info.type_id) {
+        let fn_mocker_in_scope = self
+            .shared_state
+            .fn_mockers
+            .get(&self.info.type_id)
+            .and_then(|fn_mocker| {
+                self.shared_state
+                    .find_pattern_scope(fn_mocker, self.scope)
+                    .map(|scope| (fn_mocker, scope))
+            });
+
+        let (fn_mocker, ctx) = match fn_mocker_in_scope {
+            // Patterns of other instances, e.g. the children created by `returns_mock`, are not considered,
+            // but a child without patterns of its own uses the patterns of its parent:
+            Some((fn_mocker, scope)) => (fn_mocker, Self { scope, ..*self }),
             None => {
-                return if self.info.has_default_impl {
-                    Ok(EvalResult::CallDefaultImpl)
-                } else if self.info.partial_by_default {
+                return if self.info.partial_by_default {
                     Ok(EvalResult::Unmock)
+                } else if self.info.has_default_impl {
+                    Ok(EvalResult::CallDefaultImpl)
                 } else {
                     match self.shared_state.fallback_mode {
                         FallbackMode::Error => Err(MockError::NoMockImplementation {
@@ -127,9 +145,16 @@ impl<'u, 's> DynCtx<'u, 's> {
                     }
                 }
             }
-            Some(fn_mocker) => fn_mocker,
         };
 
+        ctx.eval_fn_mocker(fn_mocker, match_inputs)
+    }
+
+    fn eval_fn_mocker(
+        &self,
+        fn_mocker: &'u FnMocker,
+        match_inputs: &dyn Fn(&CallPattern, &mut MismatchReporter) -> PatternResult<bool>,
+    ) -> MockResult<EvalResult<'u>> {
         match self.match_call_pattern(fn_mocker, match_inputs)? {
             Some((pat_index, pattern)) => match pattern.next_responder() {
                 Some(dyn_responder) => Ok(EvalResult::Responder(EvalResponder {
@@ -144,11 +169,21 @@ impl<'u, 's> DynCtx<'u, 's> {
             },
             None => match self.shared_state.fallback_mode {
                 FallbackMode::Error => {
+                    let inputs_debug = (self.input_debugger)();
                     let mut mismatches = Mismatches::new();
                     for (pat_index, call_pattern) in fn_mocker.call_patterns.iter().enumerate() {
-                        let mut mismatch_reporter = MismatchReporter::new_enabled();
-                        let _ = match_inputs(call_pattern, Some(&mut mismatch_reporter));
-                        mismatches.collect_from_reporter(PatIndex(pat_index), mismatch_reporter);
+                        if call_pattern.scope != self.scope {
+                            continue;
+                        }
+
+                        let mut mismatch_reporter =
+                            MismatchReporter::new_enabled(self.info.redacted_inputs);
+                        let _ = match_inputs(call_pattern, &mut mismatch_reporter);
+                        mismatches.collect_from_reporter(
+                            PatIndex(pat_index),
+                            mismatch_reporter,
+                            &inputs_debug,
+                        );
                     }
 
                     Err(MockError::NoMatchingCallPatterns {
@@ -164,20 +199,25 @@ impl<'u, 's> DynCtx<'u, 's> {
     fn match_call_pattern(
         &self,
         fn_mocker: &'u FnMocker,
-        match_inputs: &dyn Fn(&CallPattern, Option<&mut MismatchReporter>) -> PatternResult<bool>,
+        match_inputs: &dyn Fn(&CallPattern, &mut MismatchReporter) -> PatternResult<bool>,
     ) -> MockResult<Option<(PatIndex, &'u CallPattern)>> {
         match fn_mocker.pattern_match_mode {
             PatternMatchMode::InAnyOrder => fn_mocker
                 .call_patterns
                 .iter()
                 .enumerate()
-                .filter_map(
-                    |(pat_index, call_pattern)| match match_inputs(call_pattern, None) {
+                .filter(|(_, call_pattern)| call_pattern.scope == self.scope)
+                .filter_map(|(pat_index, call_pattern)| {
+                    let mut mismatch_reporter = MismatchReporter::new_disabled();
+                    match match_inputs(call_pattern, &mut mismatch_reporter) {
                         Ok(false) => None,
-                        Ok(true) => Some(Ok((PatIndex(pat_index), call_pattern))),
+                        Ok(true) => {
+                            mismatch_reporter.commit_snapshots();
+                            Some(Ok((PatIndex(pat_index), call_pattern)))
+                        }
                         Err(err) => Some(Err((PatIndex(pat_index), err))),
-                    },
-                )
+                    }
+                })
                 .next()
                 .transpose()
                 .map_err(|(pat_index, err)| self.map_pattern_error(err, fn_mocker, pat_index)),
@@ -186,6 +226,7 @@ impl<'u, 's> DynCtx<'u, 's> {
 
                 let (pat_index, pattern) = fn_mocker
                     .find_call_pattern_for_call_order(ordered_call_index)
+                    .filter(|(_, pattern)| pattern.scope == self.scope)
                     .ok_or_else(|| MockError::CallOrderNotMatchedForMockFn {
                         fn_call: self.fn_call(),
                         actual_call_order: error::CallOrder(ordered_call_index),
@@ -194,13 +235,18 @@ impl<'u, 's> DynCtx<'u, 's> {
                             .find_ordered_expected_call_pattern_debug(ordered_call_index),
                     })?;
 
-                let mut mismatch_reporter = MismatchReporter::new_enabled();
+                let mut mismatch_reporter =
+                    MismatchReporter::new_enabled(self.info.redacted_inputs);
 
-                if !match_inputs(pattern, Some(&mut mismatch_reporter))
+                if !match_inputs(pattern, &mut mismatch_reporter)
                     .map_err(|err| self.map_pattern_error(err, fn_mocker, pat_index))?
                 {
                     let mut mismatches = Mismatches::new();
-                    mismatches.collect_from_reporter(pat_index, mismatch_reporter);
+                    mismatches.collect_from_reporter(
+                        pat_index,
+                        mismatch_reporter,
+                        &(self.input_debugger)(),
+                    );
 
                     return Err(MockError::InputsNotMatchedInCallOrder {
                         fn_call: self.fn_call(),
@@ -210,6 +256,8 @@ impl<'u, 's> DynCtx<'u, 's> {
                     });
                 }
 
+                mismatch_reporter.commit_snapshots();
+
                 Ok(Some((pat_index, pattern)))
             }
         }
@@ -37,6 +37,12 @@ impl FnMocker {
             .map(|(index, call_pattern)| (PatIndex(index), call_pattern))
     }
 
+    pub fn has_scope(&self, scope: call_pattern::Scope) -> bool {
+        self.call_patterns
+            .iter()
+            .any(|pattern| pattern.scope == scope)
+    }
+
     pub fn debug_pattern(&self, pat_index: PatIndex) -> debug::CallPatternDebug {
         debug::CallPatternDebug::new(
             self.info,
@@ -324,23 +324,36 @@
 //!
 //! These mock APIs can be found in [mock].
 //!
+//! Mock APIs for `core` and `std` are enabled with the `mock-core` and `mock-std` features,
+//! the async IO, `Stream` and `Sink` traits of `tokio` and `futures` with `mock-tokio` and `mock-futures`,
+//! and the `Serializer` and `Deserializer` traits of `serde` with `mock-serde`.
+//!
 //!
 //! ## Misc
 //!
 //! #### What kinds of things can be mocked with unimock?
 //! * Traits with any number of methods
 //! * Traits with generic parameters, although these cannot be lifetime constrained (i.e. need to satisfy `T: 'static`).
+//! * Traits with lifetime parameters, e.g. `trait Parser<'a>`. The lifetimes are erased in the mock API, so responses referring to them have to be `'static`.
+//! * Traits with supertraits, when each supertrait is also mocked. The `supertraits=[..]` argument checks this and can re-export the supertrait mock APIs.
+//! * Traits from other crates, by mirroring the trait declaration and implementing it for a local wrapper around `Unimock` with `wrapper=..`.
 //! * Traits with associated types and constants, using `#[unimock(type T = Foo; const FOO: T = value;)]` syntax.
 //! * Methods with any self receiver (`self`, `&self`, `&mut self` or arbitrary (e.g. `self: Rc<Self>`)).
 //! * Methods that take reference inputs.
 //! * Methods returning references to self.
 //! * Methods returning references to arguments.
-//! * Methods returning `Option<&T>`, `Result<&T, E>` or `Vec<&T>` for any `T` that is borrowed from `self`.
+//! * Methods returning types that mix owned data and references borrowed from `self`, like `Option<&T>`, `Result<&T, E>`, `Vec<&T>`, `HashMap<K, &V>`, `(Option<&A>, &B)` or `Cow<'_, T>`, in any combination.
+//!   `Result` aliases with a fixed error type are supported when named `Result`, like `io::Result<&T>`.
+//!   Other generic types can be supported by implementing [MixedContainer](crate::output::MixedContainer) for them.
+//! * Methods with a `&mut self` or `Pin<&mut Self>` receiver returning mutable references borrowed from `self`, like `&mut T`, `Option<&mut T>` or `Result<&mut T, E>`.
 //! * Methods returning any tuple combination of self-borrowed or owned elements up to 4 elements.
 //! * Methods returning data borrowed from non-self arguments (these have to be converted to static reference, e.g. via [`Box::leak`](Box::leak)).
 //! * Methods returning a type containing lifetime parameters. For a mocked return they will have to be `'static`.
 //! * Generic methods using either explicit generic params or argument-position `impl Trait`.
-//! * Async methods when the trait is annotated with `#[async_trait]`.
+//! * Const generic parameters on traits and methods. Const values are selected with `with_types`, e.g. `BufferMock::read.with_types::<4>()`.
+//! * Methods returning `Box<dyn Trait>` or `Arc<dyn Trait>` of another trait mocked with `dyn_mock=true`, using [`returns_mock`](crate::build::DefineResponse::returns_mock) to respond with a child instance that is verified together with its parent.
+//! * Methods returning non-future `impl Trait`, e.g. `fn items(&self) -> impl Iterator<Item = u32>`, in crates built with Rust 1.75 or newer. The response is boxed, so it needs to be `Send + Sync + 'static`, `Box<dyn Trait>` must implement the trait and the bounds cannot refer to generic parameters. A default body is delegated to when the return type is bounded by `'static`. Nesting `impl Trait` inside the return type, like `Option<impl Trait>`, is not supported.
+//! * Async methods, either as native `async fn` or `fn -> impl Future<Output = T>` (optionally `+ Send`), or when the trait is annotated with `#[async_trait]`. Native async methods require Rust 1.75 or newer in the crate defining the trait.
 //! * Methods that return a future that is an associated type. Requires nightly.
 //!
 //! #### What kinds of traits or methods cannot be mocked?
@@ -381,6 +394,18 @@
 //! Working with associated types in a mock environment like Unimock has its limitations.
 //! The nature of associated types is that there is one type per implementation, and there is only one mock implementation, so the type must be chosen carefully.
 //!
+//! Generic associated types are expanded into their definition from the attribute when used in method outputs,
+//! so responses may borrow from `self` like any other output:
+//!
+//! ```rust
+//! # use unimock::*;
+//! #[unimock(api = LendingMock, type Item<'a> = &'a str;)]
+//! trait Lending {
+//!     type Item<'a> where Self: 'a;
+//!     fn get<'a>(&'a self) -> Option<Self::Item<'a>>;
+//! }
+//! ```
+//!
 //! #### Associated constants
 //! Associated constants in traits may be specified using the `const` keyword in the unimock macro:
 //!
@@ -470,6 +495,8 @@ mod error;
 mod eval;
 mod fn_mocker;
 mod mismatch;
+#[cfg(feature = "std")]
+mod snapshot;
 mod state;
 mod teardown;
 
@@ -482,7 +509,7 @@ use once_cell::sync::OnceCell;
 
 use assemble::MockAssembler;
 use call_pattern::DynInputMatcher;
-use debug::TraitMethodPath;
+use debug::{RedactedInputs, TraitMethodPath};
 use private::{DefaultImplDelegator, Matching};
 
 ///
@@ -578,12 +605,119 @@ use private::{DefaultImplDelegator, Matching};
 ///
 /// * `#[unimock(api=#ident), ]`: Export a mocking API as a module with the given name
 /// * `#[unimock(api=[method1, method2, ..], )]`: Instead of generating a module, generate top-level mock structs for the methods in the trait,
-///     with the names of those structs passed with array-like syntax in the same order as the methods appear in the trait definition.
+///   with the names of those structs passed with array-like syntax in the same order as the methods appear in the trait definition.
 /// * `#[unimock(unmock_with=[a, b, _], )]`: Given there are e.g. 3 methods in the annotated trait, uses the given paths as unmock implementations.
-///     The functions are assigned to the methods in the same order as the methods are listed in the trait.
-///     A value of `_` means _no unmock support_ for that method.
+///   The functions are assigned to the methods in the same order as the methods are listed in the trait.
+///   A value of `_` means _no unmock support_ for that method.
+/// * `#[unimock(partial_by_default=true, )]`: Methods with an `unmock_with` function call it when not mocked, also in a strict [Unimock].
+///   This takes precedence over the default body of the method. Requires `unmock_with`.
 /// * `#[unimock(prefix=path, )]`: Makes unimock use a different path prefix than `::unimock`, in case the crate has been re-exported through another crate.
 /// * `#[unimock(type #ident = #assoc; )]`: Specify the value of the associated type `#ident`.
+/// * `#[unimock(supertraits=[Trait1, Trait2 = Trait2Mock], )]`: Declare supertraits of the annotated trait that are mocked by their own `#[unimock]` attribute.
+///   Each entry must be one of the trait's supertrait bounds, and a compile error points to the entry when [Unimock] does not implement it.
+///   Optionally, `= path` names the existing mock API module of the supertrait, which then gets re-exported inside the `api` module under the supertrait's name.
+/// * `#[unimock(mirror=path, )]`: The annotated trait is a _mirror_ of the trait at `path`, typically defined in another crate.
+///   The mirror declaration is not emitted, only used as a description of the original trait.
+///   Methods with default bodies are declared with an empty body (`{}`).
+/// * `#[unimock(wrapper=path, )]`: Implement the trait for the local newtype `path` wrapping a [Unimock], instead of [Unimock] itself.
+///   See [mocking foreign traits](#mocking-foreign-traits).
+/// * `#[unimock(dyn_mock=true, )]`: Let [Unimock] be boxed into `dyn Trait`, so that `Box<dyn Trait>` and `Arc<dyn Trait>` can be responded to with
+///   [returns_mock](crate::build::DefineResponse::returns_mock). The trait must be dyn-compatible.
+///
+/// # Mocking foreign traits
+/// Because of the orphan rule, a trait from another crate can't be implemented for [Unimock] outside of unimock itself.
+/// Instead, declare a newtype around [Unimock] and a mirror of the foreign trait:
+///
+/// ```rust
+/// # use unimock::*;
+/// pub struct MockWriter(pub Unimock);
+///
+/// #[unimock(api=FmtWriteMock, mirror=core::fmt::Write, wrapper=MockWriter)]
+/// trait FmtWrite {
+///     fn write_str(&mut self, s: &str) -> core::fmt::Result;
+/// }
+///
+/// # fn main() {
+/// use core::fmt::Write;
+///
+/// let mut writer = MockWriter(Unimock::new(
+///     FmtWriteMock::write_str
+///         .next_call(matching!("hello"))
+///         .returns(Ok(())),
+/// ));
+///
+/// write!(writer, "hello").unwrap();
+/// # }
+/// ```
+///
+/// The wrapper must be a tuple struct with the [Unimock] as its first field,
+/// and be at least as visible as the mock API.
+/// Several mirrored traits can share the same wrapper type.
+/// Provided methods can't be mocked through the wrapper, because there is no way to delegate to the foreign default implementation.
+/// Omit them from the mirror. Calling them runs the trait's own default implementation, which in turn calls the mocked methods.
+/// Declaring a provided method is an error:
+///
+/// ```compile_fail
+/// # use unimock::*;
+/// pub struct MockWriter(pub Unimock);
+///
+/// #[unimock(api=FmtWriteMock, mirror=core::fmt::Write, wrapper=MockWriter)]
+/// trait FmtWrite {
+///     fn write_str(&mut self, s: &str) -> core::fmt::Result;
+///     fn write_char(&mut self, c: char) -> core::fmt::Result {}
+/// }
+/// ```
+///
+/// # Supertraits
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=ConfigMock)]
+/// trait Config {
+///     fn name(&self) -> String;
+/// }
+///
+/// #[unimock(api=ServiceMock, supertraits=[Config = ConfigMock])]
+/// trait Service: Config {
+///     fn serve(&self) -> i32;
+/// }
+///
+/// # fn main() {
+/// let u = Unimock::new((
+///     ServiceMock::Config::name.next_call(matching!()).returns("svc"),
+///     ServiceMock::serve.next_call(matching!()).returns(42),
+/// ));
+///
+/// assert_eq!("svc", u.name());
+/// assert_eq!(42, u.serve());
+/// # }
+/// ```
+///
+/// The re-export refers to the supertrait mock API relative to the module containing the trait,
+/// so this requires the traits to be declared at module level, not inside a function body.
+///
+/// # Parameter attributes
+/// Individual method parameters may also be annotated with `#[unimock(..)]`:
+///
+/// * `#[unimock(debug_with = path)]`: Format the argument in diagnostics using the function at `path` instead of its [Debug](core::fmt::Debug) implementation.
+///   The function has the same signature as [Debug::fmt](core::fmt::Debug::fmt), accepting a reference to the argument type (with references removed).
+///   This is useful for foreign types that do not implement `Debug`.
+/// * `#[unimock(redact)]`: Never show the argument value in diagnostics, e.g. for passwords or keys.
+///   It is displayed as `<redacted>` instead. Matching still uses the real value.
+///
+/// ```rust
+/// # use unimock::*;
+/// pub struct Foreign(i32);
+///
+/// fn fmt_foreign(foreign: &Foreign, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///     write!(f, "Foreign({})", foreign.0)
+/// }
+///
+/// #[unimock(api=TraitMock)]
+/// trait Trait {
+///     fn foo(&self, #[unimock(debug_with = fmt_foreign)] foreign: Foreign);
+///     fn login(&self, user: &str, #[unimock(redact)] password: &str) -> bool;
+/// }
+/// ```
 pub use unimock_macros::unimock;
 
 ///
@@ -699,6 +833,54 @@ pub use unimock_macros::unimock;
 /// assert_eq!("other", <Unimock as Trait>::func(&u, Data(vec![0])));
 /// ```
 ///
+/// # Matching using snapshots
+///
+/// With the `std` feature, an argument enclosed within `snapshot!("name")` is matched by comparing its pretty-printed [Debug](core::fmt::Debug) representation
+/// to the contents of the file `tests/snapshots/name.snap` in the crate being tested.
+///
+/// The snapshot file is created the first time the pattern is selected for a call, and it is rewritten whenever the pattern is selected while the environment variable `UNIMOCK_UPDATE_SNAPSHOTS` is set.
+/// Call patterns that are tried but not selected never write their snapshots.
+/// Mismatches are reported as a diff between the actual and the stored representation.
+///
+/// ```rust,no_run
+/// # use unimock::*;
+/// #[unimock(api=Mock)]
+/// trait Trait {
+///     fn render(&self, html: &str);
+/// }
+///
+/// let u = Unimock::new(
+///     Mock::render
+///         .next_call(matching!(snapshot!("rendered_page")))
+///         .returns(())
+/// );
+/// ```
+///
+/// # Matching the mutation
+///
+/// The [Mutation](MockFn::Mutation) argument is not part of the inputs, but its state at the time of the call can still be matched.
+/// Enclosing the pattern in the mutated argument's position within `mutation!(_)` matches it against a shared reference to the mutation:
+///
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=Mock)]
+/// trait Trait {
+///     fn append(&self, buf: &mut Vec<i32>);
+/// }
+///
+/// let u = Unimock::new(Mock::append.stub(|each| {
+///     each.call(matching!((mutation!(buf)) if buf.is_empty()))
+///         .mutates(|buf, _| buf.push(0));
+///     each.call(matching!(mutation!([0, ..])))
+///         .mutates(|buf, _| buf.push(1));
+/// }));
+///
+/// let mut buf = vec![];
+/// u.append(&mut buf);
+/// u.append(&mut buf);
+/// assert_eq!(vec![0, 1], buf);
+/// ```
+///
 pub use unimock_macros::matching;
 
 #[derive(Clone, Copy)]
@@ -742,6 +924,9 @@ pub struct Unimock {
 
     default_impl_delegator_cell: OnceCell<private::lib::Box<DefaultImplDelegator>>,
 
+    // The call patterns this instance can match.
+    scope: call_pattern::Scope,
+
     original_instance: bool,
     torn_down: bool,
     verify_in_drop: bool,
@@ -842,12 +1027,56 @@ impl Unimock {
         teardown::teardown_panic(&mut self);
     }
 
+    /// Verify this instance like [Termination::report](std::process::Termination::report),
+    /// writing the verification errors to `output` instead of stderr.
+    ///
+    /// This does not evaluate [TerminationMock::report](mock::std::process::TerminationMock::report).
+    #[cfg(feature = "mock-std")]
+    pub fn report_to(mut self, output: &mut impl core::fmt::Write) -> std::process::ExitCode {
+        teardown::teardown_report_with(&mut self, |error| {
+            let _ = writeln!(output, "unimock MockError: {error}");
+        })
+    }
+
+    /// Turn this instance into a closure that calls [FnMock::call](mock::core::ops::FnMock::call).
+    #[cfg(feature = "mock-core")]
+    pub fn into_fn<A, R>(self) -> impl core::ops::Fn(A) -> R
+    where
+        Unimock: mock::core::ops::Fn<A, R>,
+    {
+        move |args| mock::core::ops::Fn::call(&self, args)
+    }
+
+    /// Turn this instance into a closure that calls [FnMutMock::call_mut](mock::core::ops::FnMutMock::call_mut).
+    #[cfg(feature = "mock-core")]
+    pub fn into_fn_mut<A, R>(mut self) -> impl core::ops::FnMut(A) -> R
+    where
+        Unimock: mock::core::ops::FnMut<A, R>,
+    {
+        move |args| mock::core::ops::FnMut::call_mut(&mut self, args)
+    }
+
+    /// Turn this instance into a closure that calls [FnOnceMock::call_once](mock::core::ops::FnOnceMock::call_once).
+    #[cfg(feature = "mock-core")]
+    pub fn into_fn_once<A, R>(self) -> impl core::ops::FnOnce(A) -> R
+    where
+        Unimock: mock::core::ops::FnOnce<A, R>,
+    {
+        move |args| mock::core::ops::FnOnce::call_once(self, args)
+    }
+
+    /// Create a clone that calls [DropMock::drop](mock::core::ops::DropMock::drop) when it gets dropped.
+    #[cfg(feature = "mock-core")]
+    pub fn observe_drop(&self) -> mock::core::ops::Observed {
+        mock::core::ops::Observed(self.clone())
+    }
+
     #[track_caller]
     fn from_assembler(
         assembler_result: Result<MockAssembler, private::lib::String>,
         fallback_mode: FallbackMode,
     ) -> Self {
-        let fn_mockers = match assembler_result {
+        let (fn_mockers, scope_parents) = match assembler_result {
             Ok(assembler) => assembler.finish(),
             Err(error) => panic!("{error}"),
         };
@@ -855,10 +1084,12 @@ impl Unimock {
         Self {
             shared_state: private::lib::Arc::new(state::SharedState::new(
                 fn_mockers,
+                scope_parents,
                 fallback_mode,
             )),
             value_chain: Default::default(),
             default_impl_delegator_cell: Default::default(),
+            scope: call_pattern::Scope::ROOT,
             original_instance: true,
             torn_down: false,
             verify_in_drop: true,
@@ -891,6 +1122,13 @@ impl Unimock {
 
         panic!("{msg}")
     }
+
+    /// A clone that matches the call patterns of the given child scope.
+    fn child_instance(&self, scope: call_pattern::Scope) -> Self {
+        let mut child = self.clone();
+        child.scope = scope;
+        child
+    }
 }
 
 impl Clone for Unimock {
@@ -899,6 +1137,7 @@ impl Clone for Unimock {
             shared_state: self.shared_state.clone(),
             value_chain: Default::default(),
             default_impl_delegator_cell: Default::default(),
+            scope: self.scope,
             original_instance: false,
             torn_down: false,
             verify_in_drop: self.verify_in_drop,
@@ -956,19 +1195,10 @@ impl Drop for Unimock {
 /// Calling `report` prevents unimock from panicking later (in drop) on failed verifications, so _use with care_.
 ///
 /// # Mocking
-/// The `mock-std` feature also enables mocking of this trait through [mock::std::process::TerminationMock].
-/// This trait mock is partial by default: Unless explicitly mocked, it behaves as specified above.
-#[cfg(feature = "std")]
+/// The `mock-std` feature replaces this implementation with a mockable one, see `mock::std::process`.
+#[cfg(all(feature = "std", not(feature = "mock-std")))]
+#[allow(clippy::test_attr_in_doctest)]
 impl std::process::Termination for Unimock {
-    #[cfg(feature = "mock-std")]
-    fn report(mut self) -> std::process::ExitCode {
-        match private::eval::<mock::std::process::TerminationMock::report>(&self, (), &mut ()) {
-            private::Evaluation::Unmocked(_) => teardown::teardown_report(&mut self),
-            e => e.unwrap(&self),
-        }
-    }
-
-    #[cfg(not(feature = "mock-std"))]
     fn report(mut self) -> std::process::ExitCode {
         teardown::teardown_report(&mut self)
     }
@@ -1113,6 +1343,7 @@ pub struct MockFnInfo {
     path: TraitMethodPath,
     has_default_impl: bool,
     partial_by_default: bool,
+    redacted_inputs: RedactedInputs,
 }
 
 impl MockFnInfo {
@@ -1127,6 +1358,7 @@ impl MockFnInfo {
             path: TraitMethodPath::default(),
             has_default_impl: false,
             partial_by_default: false,
+            redacted_inputs: RedactedInputs::NONE,
         }
     }
 
@@ -1148,6 +1380,22 @@ impl MockFnInfo {
             ..self
         }
     }
+
+    /// Fall back to the unmocked implementation when the method is not mocked, even in a strict [Unimock]
+    pub const fn partial_by_default(self) -> Self {
+        Self {
+            partial_by_default: true,
+            ..self
+        }
+    }
+
+    /// Hide the values of the inputs at the given indexes from diagnostics
+    pub const fn redact_inputs(self, input_indexes: &[usize]) -> Self {
+        Self {
+            redacted_inputs: RedactedInputs::new(input_indexes),
+            ..self
+        }
+    }
 }
 
 /// A type that indicates a mutated argument.
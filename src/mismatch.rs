@@ -16,8 +16,19 @@ impl Mismatches {
         Self { mismatches: vec![] }
     }
 
-    pub fn collect_from_reporter(&mut self, pat_index: PatIndex, reporter: MismatchReporter) {
-        for (input_index, mismatch) in reporter.mismatches {
+    /// Collect mismatches from a reporter.
+    ///
+    /// Actual values that could not be debugged by the matcher get their representation from `inputs_debug`.
+    pub fn collect_from_reporter(
+        &mut self,
+        pat_index: PatIndex,
+        reporter: MismatchReporter,
+        inputs_debug: &[Option<String>],
+    ) {
+        for (input_index, mut mismatch) in reporter.mismatches {
+            if mismatch.actual.is_none() && !mismatch.redacted {
+                mismatch.actual = inputs_debug.get(input_index.0).cloned().flatten();
+            }
             self.mismatches.push((pat_index, input_index, mismatch));
         }
     }
@@ -45,9 +56,19 @@ impl Display for Mismatches {
                 kind,
                 actual,
                 expected,
+                redacted,
             } = mismatch;
             let mut header_msg = MismatchMsg::new(*pat_index, *input_index, is_unique_pat, *kind);
 
+            if *redacted {
+                header_msg.fmt(f)?;
+                writeln!(
+                    f,
+                    "Diagnostics are not available because the input is redacted."
+                )?;
+                continue;
+            }
+
             match (kind, actual, expected) {
                 (MismatchKind::Pattern, Some(actual), Some(expected)) => {
                     header_msg.has_comparison = true;
@@ -79,6 +100,25 @@ impl Display for Mismatches {
                         Diff::new(actual, expected).fmt(f)?;
                     }
                 }
+                (MismatchKind::Eq, Some(actual), None) => {
+                    header_msg.fmt(f)?;
+                    writeln!(f, "Actual value did not equal expected value (expected value has no Debug representation). Actual value:")?;
+                    write!(f, "{actual}")?;
+                }
+                (MismatchKind::Ne, Some(actual), None) => {
+                    header_msg.fmt(f)?;
+                    writeln!(f, "Actual value unexpectedly equalled expected value (expected value has no Debug representation). Actual value:")?;
+                    write!(f, "{actual}")?;
+                }
+                (MismatchKind::Snapshot, Some(actual), Some(expected)) => {
+                    header_msg.has_comparison = true;
+                    header_msg.fmt(f)?;
+                    Diff::new(actual, expected).fmt(f)?;
+                }
+                (MismatchKind::Snapshot, _, _) => {
+                    header_msg.fmt(f)?;
+                    writeln!(f, "The snapshot file could not be read.")?;
+                }
                 (MismatchKind::Pattern, _, _) => {
                     header_msg.fmt(f)?;
                     writeln!(f, "Actual value did not match expected pattern, but can't display diagnostics because the type is likely missing #[derive(Debug)].")?;
@@ -103,6 +143,7 @@ pub(crate) struct Mismatch {
     pub kind: MismatchKind,
     pub actual: Option<String>,
     pub expected: Option<String>,
+    pub redacted: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -110,6 +151,7 @@ pub(crate) enum MismatchKind {
     Pattern,
     Eq,
     Ne,
+    Snapshot,
 }
 
 struct MismatchMsg {
@@ -143,6 +185,7 @@ impl Display for MismatchMsg {
             MismatchKind::Pattern => "Pattern mismatch for ",
             MismatchKind::Eq => "Equality mismatch for ",
             MismatchKind::Ne => "Inequality mismatch for ",
+            MismatchKind::Snapshot => "Snapshot mismatch for ",
         };
 
         write!(f, "{initial_msg}")?;
@@ -157,7 +200,9 @@ impl Display for MismatchMsg {
             )?;
         }
 
-        if let MismatchKind::Pattern | MismatchKind::Eq = self.mismatch_kind {
+        if let MismatchKind::Pattern | MismatchKind::Eq | MismatchKind::Snapshot =
+            self.mismatch_kind
+        {
             if self.has_comparison {
                 write!(f, " (actual / expected)")?;
             }
@@ -1,4 +1,95 @@
 //! Mock APIs for `core` traits
+//!
+//! # Clone
+//! [Clone] is not mockable, because `Unimock` implements it for sharing mock state:
+//! A clone is a new handle to the same clauses, call counters and call order as the original instance.
+//! Code under test that clones a `Unimock` (e.g. through a `T: Clone` bound) therefore keeps calling the same mocks,
+//! and its calls are verified when the original instance is dropped.
+//! The original instance must outlive its clones.
+
+/// A [Unimock](crate::Unimock) implementing the real traits that are mirrored with a generic parameter.
+///
+/// Traits like [Iterator] or [Deref](core::ops::Deref) select their item or target through an associated type,
+/// so `Unimock` can't implement them for every type at once.
+/// Instead, `Typed<T>` fixes that type to `T` and delegates to the mirrored traits of `Unimock`, mocked with `with_types`:
+///
+/// - [Iterator], [DoubleEndedIterator] and [ExactSizeIterator] yield items of type `T` through [`mock::core::iter`](iter).
+/// - [Index](core::ops::Index), [IndexMut](core::ops::IndexMut), [Deref](core::ops::Deref) and [DerefMut](core::ops::DerefMut) borrow a `T` through [`mock::core::ops`](ops).
+/// - `Stream` yields items of type `T` through `mock::futures::stream` with the `mock-futures` feature.
+///
+/// Methods that several of these traits share, like `collect` of [Iterator] and `StreamExt`, need fully qualified syntax.
+#[cfg(feature = "mock-core")]
+pub struct Typed<T: ?Sized> {
+    pub(crate) unimock: crate::Unimock,
+    ty: core::marker::PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "mock-core")]
+impl<T: ?Sized> Typed<T> {
+    /// Wrap a `Unimock`, fixing the type of the real traits to `T`.
+    pub fn new(unimock: crate::Unimock) -> Self {
+        Self {
+            unimock,
+            ty: core::marker::PhantomData,
+        }
+    }
+
+    /// Get back the wrapped `Unimock`.
+    pub fn into_inner(self) -> crate::Unimock {
+        self.unimock
+    }
+}
+
+#[cfg(feature = "mock-core")]
+impl<T: ?Sized> From<crate::Unimock> for Typed<T> {
+    fn from(unimock: crate::Unimock) -> Self {
+        Self::new(unimock)
+    }
+}
+
+/// A [Unimock](crate::Unimock) implementing [PartialEq], [Eq], [PartialOrd], [Ord], [Hash](core::hash::Hash) and [Default].
+///
+/// `Unimock` itself doesn't implement these traits, because [Eq] and [Ord] promise laws that a mock can't keep by itself,
+/// and a mocked value silently becoming usable as e.g. a map key would hide mistakes.
+/// `Keyed` opts into them, for using mocks in sorted and hash based collections:
+///
+/// - [PartialEq], [PartialOrd] and [Ord] call their mocks in [`mock::core::cmp`](cmp).
+/// - [Hash](core::hash::Hash) calls [`HashMock`](hash::HashMock), which receives the hasher as a `dyn Hasher`.
+/// - [Eq] is implemented as a marker. It promises that `eq` is reflexive, which only holds when [`PartialEqMock::eq`](cmp::PartialEqMock::eq)
+///   is mocked to return `true` for comparisons of an instance with itself.
+/// - [Default] wraps an instance without any mocks, i.e. `Unimock::new(())`.
+///
+/// A clone shares the mock state of the original instance.
+#[cfg(feature = "mock-core")]
+#[derive(Clone)]
+pub struct Keyed(crate::Unimock);
+
+#[cfg(feature = "mock-core")]
+impl Keyed {
+    /// Wrap a `Unimock`.
+    pub fn new(unimock: crate::Unimock) -> Self {
+        Self(unimock)
+    }
+
+    /// Get back the wrapped `Unimock`.
+    pub fn into_inner(self) -> crate::Unimock {
+        self.0
+    }
+}
+
+#[cfg(feature = "mock-core")]
+impl From<crate::Unimock> for Keyed {
+    fn from(unimock: crate::Unimock) -> Self {
+        Self::new(unimock)
+    }
+}
+
+#[cfg(feature = "mock-core")]
+impl Default for Keyed {
+    fn default() -> Self {
+        Self::new(crate::Unimock::new(()))
+    }
+}
 
 /// Mock APIs for `core::fmt` traits
 #[cfg(feature = "mock-core")]
@@ -14,6 +105,69 @@ pub mod fmt {
     pub trait Debug {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
     }
+
+    #[unimock(prefix=crate, api=WriteMock, mirror=core::fmt::Write)]
+    pub trait Write {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result;
+        fn write_char(&mut self, c: char) -> core::fmt::Result {}
+        fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {}
+    }
+}
+
+/// Mock APIs for `core::cmp` traits
+///
+/// The traits are implemented for [Keyed], and the other operand of a comparison is also a [Keyed],
+/// typically a clone sharing the same mock state.
+/// In diagnostics it is shown as `Keyed`, so comparisons don't depend on a mocked [Debug](core::fmt::Debug).
+///
+/// Only the required methods are mocked. Provided methods like `ne` or `lt` use their default implementations,
+/// which call the mocked `eq` and `partial_cmp`.
+/// This makes it possible to verify how many comparisons an algorithm performs:
+///
+/// ```rust
+/// use unimock::*;
+/// use unimock::mock::core::{cmp::PartialOrdMock, Keyed};
+/// use core::cmp::Ordering;
+///
+/// let a = Keyed::new(Unimock::new(
+///     PartialOrdMock::partial_cmp
+///         .next_call(matching!(_))
+///         .returns(Some(Ordering::Less)),
+/// ));
+/// let b = a.clone();
+///
+/// assert!(a < b);
+/// ```
+#[cfg(feature = "mock-core")]
+// `partial_cmp` is mocked independently of `cmp`:
+#[allow(clippy::non_canonical_partial_ord_impl)]
+pub mod cmp {
+    use core::cmp::Ordering;
+
+    use unimock_macros::unimock;
+
+    use super::Keyed;
+
+    fn fmt_keyed(_: &Keyed, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Keyed")
+    }
+
+    #[unimock(prefix=crate, api=PartialEqMock, mirror=core::cmp::PartialEq, wrapper=Keyed)]
+    pub trait PartialEq {
+        fn eq(&self, #[unimock(debug_with = fmt_keyed)] other: &Self) -> bool;
+    }
+
+    impl Eq for Keyed {}
+
+    #[unimock(prefix=crate, api=PartialOrdMock, mirror=core::cmp::PartialOrd, wrapper=Keyed)]
+    pub trait PartialOrd: PartialEq {
+        fn partial_cmp(&self, #[unimock(debug_with = fmt_keyed)] other: &Self) -> Option<Ordering>;
+    }
+
+    #[unimock(prefix=crate, api=OrdMock, mirror=core::cmp::Ord, wrapper=Keyed)]
+    pub trait Ord: Eq + PartialOrd {
+        fn cmp(&self, #[unimock(debug_with = fmt_keyed)] other: &Self) -> Ordering;
+    }
 }
 
 /// Mock APIs for `core::hash` traits
@@ -21,8 +175,40 @@ pub mod fmt {
 pub mod hash {
     use unimock_macros::unimock;
 
-    // Note: `Hash` can't be implemented because `H: Hasher`
-    // doesn't have a static bound
+    use super::Keyed;
+
+    /// [core::hash::Hash] with the hasher passed as a `dyn Hasher`, mocked through `HashMock`.
+    ///
+    /// `Hash::hash` is generic over the [Hasher](core::hash::Hasher), so it can't be mirrored directly.
+    /// [Keyed] implements [core::hash::Hash] by calling this trait, which receives the hasher as its mutation:
+    ///
+    /// ```rust
+    /// use unimock::*;
+    /// use unimock::mock::core::{cmp::PartialEqMock, hash::HashMock, Keyed};
+    /// use std::collections::HashSet;
+    ///
+    /// let a = Keyed::new(Unimock::new((
+    ///     HashMock::hash
+    ///         .each_call(matching!())
+    ///         .mutates(|state, _| state.write_u8(1)),
+    ///     PartialEqMock::eq.each_call(matching!(_)).returns(true),
+    /// )));
+    ///
+    /// let mut set = HashSet::new();
+    /// set.insert(a.clone());
+    /// assert!(set.contains(&a));
+    /// ```
+    #[unimock(prefix=crate, api=HashMock)]
+    pub trait Hash {
+        /// Feed this value into the given hasher.
+        fn hash(&self, state: &mut dyn core::hash::Hasher);
+    }
+
+    impl core::hash::Hash for Keyed {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            Hash::hash(&self.0, state)
+        }
+    }
 
     #[unimock(prefix=crate, api=HasherMock, mirror=core::hash::Hasher)]
     pub trait Hasher {
@@ -49,3 +235,276 @@ pub mod hash {
         // fn write_str(&mut self, s: &str) {}
     }
 }
+
+/// Mock APIs for `core::iter` traits
+///
+/// `Iterator::Item` is an associated type, so `Unimock` can't implement [Iterator] for every item type at once.
+/// Instead, the iterator traits are mirrored with the item type as a generic parameter,
+/// which is selected in the mock API with `with_types`.
+/// [Typed] turns a `Unimock` into a real iterator yielding items of that type.
+///
+/// # Example
+/// ```rust
+/// use unimock::*;
+/// use unimock::mock::core::{iter::IteratorMock, Typed};
+///
+/// let iter = Typed::<i32>::new(Unimock::new((
+///     IteratorMock::next.with_types::<i32>().next_call(matching!()).returns(Some(1)),
+///     IteratorMock::next.with_types::<i32>().next_call(matching!()).returns(Some(2)),
+///     IteratorMock::next.with_types::<i32>().next_call(matching!()).returns(None),
+/// )));
+///
+/// assert_eq!(vec![1, 2], iter.collect::<Vec<_>>());
+/// ```
+#[cfg(feature = "mock-core")]
+pub mod iter {
+    use unimock_macros::unimock;
+
+    use super::Typed;
+    use crate::Unimock;
+
+    /// Mirror of [Iterator](core::iter::Iterator) with the item type as a generic parameter.
+    #[unimock(prefix=crate, api=IteratorMock)]
+    pub trait Iterator<T> {
+        /// Advance the iterator and return the next item.
+        fn next(&mut self) -> Option<T>;
+
+        /// Bounds on the remaining length of the iterator.
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, None)
+        }
+    }
+
+    /// Mirror of [DoubleEndedIterator](core::iter::DoubleEndedIterator) with the item type as a generic parameter.
+    #[unimock(prefix=crate, api=DoubleEndedIteratorMock)]
+    pub trait DoubleEndedIterator<T> {
+        /// Remove and return an item from the end of the iterator.
+        fn next_back(&mut self) -> Option<T>;
+    }
+
+    /// Mirror of [ExactSizeIterator](core::iter::ExactSizeIterator) with the item type as a generic parameter.
+    #[allow(clippy::len_without_is_empty)]
+    #[unimock(prefix=crate, api=ExactSizeIteratorMock)]
+    pub trait ExactSizeIterator<T> {
+        /// The exact remaining length of the iterator.
+        fn len(&self) -> usize;
+    }
+
+    impl<T> core::iter::Iterator for Typed<T>
+    where
+        Unimock: Iterator<T>,
+    {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            Iterator::next(&mut self.unimock)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            Iterator::size_hint(&self.unimock)
+        }
+    }
+
+    impl<T> core::iter::DoubleEndedIterator for Typed<T>
+    where
+        Unimock: Iterator<T> + DoubleEndedIterator<T>,
+    {
+        fn next_back(&mut self) -> Option<T> {
+            DoubleEndedIterator::next_back(&mut self.unimock)
+        }
+    }
+
+    impl<T> core::iter::ExactSizeIterator for Typed<T>
+    where
+        Unimock: Iterator<T> + ExactSizeIterator<T>,
+    {
+        fn len(&self) -> usize {
+            ExactSizeIterator::len(&self.unimock)
+        }
+    }
+}
+
+/// Mock APIs for `core::ops` traits
+///
+/// # Callables
+/// The closure traits [Fn], [FnMut] and [FnOnce] can't be implemented on stable Rust.
+/// They are mirrored with the argument and return types as generic parameters instead,
+/// and [Unimock::into_fn](crate::Unimock::into_fn) and friends turn a `Unimock` into a real closure.
+/// A closure taking several arguments is mocked with a tuple of those arguments.
+///
+/// ```rust
+/// use unimock::*;
+/// use unimock::mock::core::ops::FnMock;
+///
+/// fn apply_twice(f: impl Fn(i32) -> i32, input: i32) -> i32 {
+///     f(f(input))
+/// }
+///
+/// let f = Unimock::new(
+///     FnMock::call
+///         .with_types::<i32, i32>()
+///         .each_call(matching!(n))
+///         .answers(|n| n * 10),
+/// )
+/// .into_fn();
+///
+/// assert_eq!(200, apply_twice(f, 2));
+/// ```
+///
+/// # Indexing and dereferencing
+/// [Index](core::ops::Index) and [Deref](core::ops::Deref) determine their output through an associated type,
+/// so they are mirrored with the output as a generic parameter.
+/// [Typed] wraps a `Unimock` to implement the real traits for a chosen output type.
+/// The output is borrowed from the instance, so a response like `.returns("value".to_string())` is borrowed as a `&str`.
+///
+/// # Drop
+/// `Unimock` implements [Drop] for verifying its mocks, so dropping can't be mocked directly.
+/// Instead, [Unimock::observe_drop](crate::Unimock::observe_drop) creates an [Observed](ops::Observed) clone that calls [DropMock::drop](ops::DropMock::drop) when dropped,
+/// e.g. to verify that the code under test releases a resource:
+///
+/// ```rust
+/// use unimock::*;
+/// use unimock::mock::core::ops::DropMock;
+///
+/// fn release<T>(_resource: T) {}
+///
+/// let u = Unimock::new(DropMock::drop.next_call(matching!()).returns(()));
+/// release(u.observe_drop());
+/// ```
+///
+/// Unless mocked, dropping an observed clone does nothing.
+#[cfg(feature = "mock-core")]
+pub mod ops {
+    use unimock_macros::unimock;
+
+    use super::Typed;
+    use crate::Unimock;
+
+    /// Mirror of [Fn](core::ops::Fn) with the argument and return types as generic parameters.
+    #[unimock(prefix=crate, api=FnMock)]
+    pub trait Fn<A, R> {
+        /// Call the function.
+        fn call(&self, args: A) -> R;
+    }
+
+    /// Mirror of [FnMut](core::ops::FnMut) with the argument and return types as generic parameters.
+    #[unimock(prefix=crate, api=FnMutMock)]
+    pub trait FnMut<A, R> {
+        /// Call the function.
+        fn call_mut(&mut self, args: A) -> R;
+    }
+
+    /// Mirror of [FnOnce](core::ops::FnOnce) with the argument and return types as generic parameters.
+    #[unimock(prefix=crate, api=FnOnceMock)]
+    pub trait FnOnce<A, R> {
+        /// Call the function.
+        fn call_once(self, args: A) -> R;
+    }
+
+    /// Mirror of [Index](core::ops::Index) with the output type as a generic parameter.
+    #[unimock(prefix=crate, api=IndexMock)]
+    pub trait Index<Idx, T: ?Sized> {
+        /// Borrow the element at `index`.
+        fn index(&self, index: Idx) -> &T;
+    }
+
+    /// Mirror of [IndexMut](core::ops::IndexMut) with the output type as a generic parameter.
+    #[unimock(prefix=crate, api=IndexMutMock)]
+    pub trait IndexMut<Idx, T: ?Sized> {
+        /// Mutably borrow the element at `index`.
+        fn index_mut(&mut self, index: Idx) -> &mut T;
+    }
+
+    /// Mirror of [Deref](core::ops::Deref) with the target type as a generic parameter.
+    #[unimock(prefix=crate, api=DerefMock)]
+    pub trait Deref<T: ?Sized> {
+        /// Borrow the target.
+        fn deref(&self) -> &T;
+    }
+
+    /// Mirror of [DerefMut](core::ops::DerefMut) with the target type as a generic parameter.
+    #[unimock(prefix=crate, api=DerefMutMock)]
+    pub trait DerefMut<T: ?Sized> {
+        /// Mutably borrow the target.
+        fn deref_mut(&mut self) -> &mut T;
+    }
+
+    /// Observation of dropping a clone created with [Unimock::observe_drop].
+    #[unimock(prefix=crate, api=DropMock, unmock_with=[drop_unmocked], partial_by_default=true)]
+    pub trait Drop {
+        /// The clone is being dropped.
+        fn drop(&mut self);
+    }
+
+    fn drop_unmocked(_: &mut Unimock) {}
+
+    /// A clone of a [Unimock] that calls [DropMock::drop] when it gets dropped.
+    ///
+    /// It dereferences to the clone, and further clones of that are not observed.
+    pub struct Observed(pub(crate) Unimock);
+
+    impl core::ops::Deref for Observed {
+        type Target = Unimock;
+
+        fn deref(&self) -> &Unimock {
+            &self.0
+        }
+    }
+
+    impl core::ops::DerefMut for Observed {
+        fn deref_mut(&mut self) -> &mut Unimock {
+            &mut self.0
+        }
+    }
+
+    impl core::ops::Drop for Observed {
+        fn drop(&mut self) {
+            #[cfg(feature = "std")]
+            if std::thread::panicking() {
+                return;
+            }
+
+            Drop::drop(&mut self.0);
+        }
+    }
+
+    impl<Idx, T: ?Sized> core::ops::Index<Idx> for Typed<T>
+    where
+        Unimock: Index<Idx, T>,
+    {
+        type Output = T;
+
+        fn index(&self, index: Idx) -> &T {
+            Index::index(&self.unimock, index)
+        }
+    }
+
+    impl<Idx, T: ?Sized> core::ops::IndexMut<Idx> for Typed<T>
+    where
+        Unimock: Index<Idx, T> + IndexMut<Idx, T>,
+    {
+        fn index_mut(&mut self, index: Idx) -> &mut T {
+            IndexMut::index_mut(&mut self.unimock, index)
+        }
+    }
+
+    impl<T: ?Sized> core::ops::Deref for Typed<T>
+    where
+        Unimock: Deref<T>,
+    {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            Deref::deref(&self.unimock)
+        }
+    }
+
+    impl<T: ?Sized> core::ops::DerefMut for Typed<T>
+    where
+        Unimock: Deref<T> + DerefMut<T>,
+    {
+        fn deref_mut(&mut self) -> &mut T {
+            DerefMut::deref_mut(&mut self.unimock)
+        }
+    }
+}
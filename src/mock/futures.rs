@@ -0,0 +1,122 @@
+//! Mock APIs for `futures` traits
+
+/// Mock APIs for `futures::io` traits
+#[cfg(feature = "mock-futures")]
+pub mod io {
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use std::io::{IoSlice, IoSliceMut, Result, SeekFrom};
+
+    use unimock_macros::unimock;
+
+    #[unimock(prefix=crate, api=AsyncBufReadMock, mirror=futures_io::AsyncBufRead)]
+    pub trait AsyncBufRead: AsyncRead {
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<&[u8]>>;
+        fn consume(self: Pin<&mut Self>, amt: usize);
+    }
+
+    #[unimock(prefix=crate, api=AsyncReadMock, mirror=futures_io::AsyncRead)]
+    pub trait AsyncRead {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<Result<usize>>;
+        fn poll_read_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &mut [IoSliceMut<'_>],
+        ) -> Poll<Result<usize>> {
+        }
+    }
+
+    #[unimock(prefix=crate, api=AsyncSeekMock, mirror=futures_io::AsyncSeek)]
+    pub trait AsyncSeek {
+        fn poll_seek(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            pos: SeekFrom,
+        ) -> Poll<Result<u64>>;
+    }
+
+    #[unimock(prefix=crate, api=AsyncWriteMock, mirror=futures_io::AsyncWrite)]
+    pub trait AsyncWrite {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<Result<usize>>;
+        fn poll_write_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &[IoSlice<'_>],
+        ) -> Poll<Result<usize>> {
+        }
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>>;
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>>;
+    }
+}
+
+/// Mock APIs for `futures::stream` traits
+///
+/// `Stream::Item` is an associated type, so `Unimock` can't implement [Stream](futures_core::Stream) for every item type at once.
+/// Like [`mock::core::iter`](crate::mock::core::iter), the trait is mirrored with the item type as a generic parameter,
+/// and [Typed](crate::mock::core::Typed) turns a `Unimock` into a real stream yielding items of that type.
+#[cfg(feature = "mock-futures")]
+pub mod stream {
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    use unimock_macros::unimock;
+
+    use crate::mock::core::Typed;
+    use crate::Unimock;
+
+    /// Mirror of [Stream](futures_core::Stream) with the item type as a generic parameter.
+    #[unimock(prefix=crate, api=StreamMock)]
+    pub trait Stream<T> {
+        /// Attempt to pull out the next value of the stream.
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>>;
+
+        /// Bounds on the remaining length of the stream.
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, None)
+        }
+    }
+
+    impl<T> futures_core::Stream for Typed<T>
+    where
+        Unimock: Stream<T>,
+    {
+        type Item = T;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            Stream::poll_next(Pin::new(&mut self.get_mut().unimock), cx)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            Stream::size_hint(&self.unimock)
+        }
+    }
+}
+
+/// Mock APIs for `futures::sink` traits
+#[cfg(feature = "mock-futures")]
+pub mod sink {
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    use unimock_macros::unimock;
+
+    /// The sink is generic over the item type, selected in the mock API with `with_types`.
+    /// Sink errors are [std::io::Error].
+    #[unimock(prefix=crate, api=SinkMock, mirror=futures_sink::Sink, type Error = std::io::Error;)]
+    pub trait Sink<Item> {
+        type Error;
+
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+        fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error>;
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+    }
+}
@@ -1,2 +1,5 @@
 pub mod core;
+pub mod futures;
+pub mod serde;
 pub mod std;
+pub mod tokio;
@@ -0,0 +1,935 @@
+//! Mock APIs for `serde` traits
+//!
+//! The serde traits are generic over the values being (de)serialized, e.g. `serialize_field<T: ?Sized + Serialize>`.
+//! Such methods can't be mocked with `with_types`, because the value types are not `'static`, and often not even nameable.
+//! Instead, every serde trait has a local counterpart without the generic value, which is what gets mocked.
+//! [Unimock](crate::Unimock) implements the real serde traits by calling the mocked method first,
+//! and then (de)serializing the value through a clone of itself, i.e. through further calls to the same mock APIs.
+//!
+//! Compound (de)serializers like [SerializeStruct](::serde::ser::SerializeStruct) or [SeqAccess](::serde::de::SeqAccess) are the `Unimock` itself.
+//! This way, a test can verify the exact sequence of calls a `Serialize` or `Deserialize` implementation makes.
+
+#[cfg(feature = "mock-serde")]
+use crate::private::lib::String;
+
+/// The error type of the mocked serde traits.
+#[cfg(feature = "mock-serde")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+}
+
+#[cfg(feature = "mock-serde")]
+impl Error {
+    /// Create an error with the given message.
+    pub fn new(message: impl core::fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+        }
+    }
+
+    /// The error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(feature = "mock-serde")]
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(feature = "mock-serde")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "mock-serde")]
+impl ::serde::ser::Error for Error {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+}
+
+#[cfg(feature = "mock-serde")]
+impl ::serde::de::Error for Error {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+}
+
+/// Mock APIs for `serde::ser` traits
+///
+/// A `Unimock` serializes with `Ok = ()`, and all the compound serializers are `Unimock` too.
+/// Methods starting a compound respond with `Ok(())` to continue with the compound serializer.
+///
+/// ```rust
+/// use serde::Serialize;
+/// use unimock::*;
+/// use unimock::mock::serde::ser::{SerializeStructMock, SerializerMock};
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let u = Unimock::new((
+///     SerializerMock::serialize_struct
+///         .next_call(matching!("Point", 2))
+///         .returns(Ok(())),
+///     SerializeStructMock::serialize_field
+///         .next_call(matching!("x"))
+///         .returns(Ok(())),
+///     SerializerMock::serialize_i32
+///         .next_call(matching!(1))
+///         .returns(Ok(())),
+///     SerializeStructMock::serialize_field
+///         .next_call(matching!("y"))
+///         .returns(Ok(())),
+///     SerializerMock::serialize_i32
+///         .next_call(matching!(2))
+///         .returns(Ok(())),
+///     SerializeStructMock::end.next_call(matching!()).returns(Ok(())),
+/// ));
+///
+/// Point { x: 1, y: 2 }.serialize(u).unwrap();
+/// ```
+#[cfg(feature = "mock-serde")]
+pub mod ser {
+    use ::serde::ser::Serialize;
+    use unimock_macros::unimock;
+
+    use super::Error;
+    use crate::Unimock;
+
+    /// Mockable counterpart of [Serializer](::serde::Serializer).
+    ///
+    /// Methods taking a value, like `serialize_some`, serialize the value after the mocked call.
+    #[unimock(prefix=crate, api=SerializerMock)]
+    pub trait Serializer {
+        /// Serialize a `bool`.
+        fn serialize_bool(&self, v: bool) -> Result<(), Error>;
+        /// Serialize an `i8`.
+        fn serialize_i8(&self, v: i8) -> Result<(), Error>;
+        /// Serialize an `i16`.
+        fn serialize_i16(&self, v: i16) -> Result<(), Error>;
+        /// Serialize an `i32`.
+        fn serialize_i32(&self, v: i32) -> Result<(), Error>;
+        /// Serialize an `i64`.
+        fn serialize_i64(&self, v: i64) -> Result<(), Error>;
+        /// Serialize an `i128`.
+        fn serialize_i128(&self, v: i128) -> Result<(), Error>;
+        /// Serialize a `u8`.
+        fn serialize_u8(&self, v: u8) -> Result<(), Error>;
+        /// Serialize a `u16`.
+        fn serialize_u16(&self, v: u16) -> Result<(), Error>;
+        /// Serialize a `u32`.
+        fn serialize_u32(&self, v: u32) -> Result<(), Error>;
+        /// Serialize a `u64`.
+        fn serialize_u64(&self, v: u64) -> Result<(), Error>;
+        /// Serialize a `u128`.
+        fn serialize_u128(&self, v: u128) -> Result<(), Error>;
+        /// Serialize an `f32`.
+        fn serialize_f32(&self, v: f32) -> Result<(), Error>;
+        /// Serialize an `f64`.
+        fn serialize_f64(&self, v: f64) -> Result<(), Error>;
+        /// Serialize a `char`.
+        fn serialize_char(&self, v: char) -> Result<(), Error>;
+        /// Serialize a `&str`.
+        fn serialize_str(&self, v: &str) -> Result<(), Error>;
+        /// Serialize a chunk of raw bytes.
+        fn serialize_bytes(&self, v: &[u8]) -> Result<(), Error>;
+        /// Serialize a `None` value.
+        fn serialize_none(&self) -> Result<(), Error>;
+        /// Serialize a `Some(T)` value, before serializing the `T`.
+        fn serialize_some(&self) -> Result<(), Error>;
+        /// Serialize a `()` value.
+        fn serialize_unit(&self) -> Result<(), Error>;
+        /// Serialize a unit struct like `struct Unit`.
+        fn serialize_unit_struct(&self, name: &'static str) -> Result<(), Error>;
+        /// Serialize a unit variant like `E::A` in `enum E { A, B }`.
+        fn serialize_unit_variant(
+            &self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+        ) -> Result<(), Error>;
+        /// Serialize a newtype struct like `struct Millimeters(u8)`, before serializing the inner value.
+        fn serialize_newtype_struct(&self, name: &'static str) -> Result<(), Error>;
+        /// Serialize a newtype variant like `E::N` in `enum E { N(u8) }`, before serializing the inner value.
+        fn serialize_newtype_variant(
+            &self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+        ) -> Result<(), Error>;
+        /// Begin to serialize a sequence.
+        fn serialize_seq(&self, len: Option<usize>) -> Result<(), Error>;
+        /// Begin to serialize a tuple.
+        fn serialize_tuple(&self, len: usize) -> Result<(), Error>;
+        /// Begin to serialize a tuple struct like `struct Rgb(u8, u8, u8)`.
+        fn serialize_tuple_struct(&self, name: &'static str, len: usize) -> Result<(), Error>;
+        /// Begin to serialize a tuple variant like `E::T` in `enum E { T(u8, u8) }`.
+        fn serialize_tuple_variant(
+            &self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize,
+        ) -> Result<(), Error>;
+        /// Begin to serialize a map.
+        fn serialize_map(&self, len: Option<usize>) -> Result<(), Error>;
+        /// Begin to serialize a struct like `struct Rgb { r: u8, g: u8, b: u8 }`.
+        fn serialize_struct(&self, name: &'static str, len: usize) -> Result<(), Error>;
+        /// Begin to serialize a struct variant like `E::S` in `enum E { S { r: u8, g: u8, b: u8 } }`.
+        fn serialize_struct_variant(
+            &self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize,
+        ) -> Result<(), Error>;
+        /// Whether `Serialize` implementations should serialize in human-readable form.
+        fn is_human_readable(&self) -> bool {
+            true
+        }
+    }
+
+    /// Mockable counterpart of [SerializeSeq](::serde::ser::SerializeSeq).
+    #[unimock(prefix=crate, api=SerializeSeqMock)]
+    pub trait SerializeSeq {
+        /// Serialize a sequence element, before serializing the element value.
+        fn serialize_element(&mut self) -> Result<(), Error>;
+        /// Finish serializing the sequence.
+        fn end(self) -> Result<(), Error>;
+    }
+
+    /// Mockable counterpart of [SerializeTuple](::serde::ser::SerializeTuple).
+    #[unimock(prefix=crate, api=SerializeTupleMock)]
+    pub trait SerializeTuple {
+        /// Serialize a tuple element, before serializing the element value.
+        fn serialize_element(&mut self) -> Result<(), Error>;
+        /// Finish serializing the tuple.
+        fn end(self) -> Result<(), Error>;
+    }
+
+    /// Mockable counterpart of [SerializeTupleStruct](::serde::ser::SerializeTupleStruct).
+    #[unimock(prefix=crate, api=SerializeTupleStructMock)]
+    pub trait SerializeTupleStruct {
+        /// Serialize a tuple struct field, before serializing the field value.
+        fn serialize_field(&mut self) -> Result<(), Error>;
+        /// Finish serializing the tuple struct.
+        fn end(self) -> Result<(), Error>;
+    }
+
+    /// Mockable counterpart of [SerializeTupleVariant](::serde::ser::SerializeTupleVariant).
+    #[unimock(prefix=crate, api=SerializeTupleVariantMock)]
+    pub trait SerializeTupleVariant {
+        /// Serialize a tuple variant field, before serializing the field value.
+        fn serialize_field(&mut self) -> Result<(), Error>;
+        /// Finish serializing the tuple variant.
+        fn end(self) -> Result<(), Error>;
+    }
+
+    /// Mockable counterpart of [SerializeMap](::serde::ser::SerializeMap).
+    #[unimock(prefix=crate, api=SerializeMapMock)]
+    pub trait SerializeMap {
+        /// Serialize a map key, before serializing the key value.
+        fn serialize_key(&mut self) -> Result<(), Error>;
+        /// Serialize a map value, before serializing the value.
+        fn serialize_value(&mut self) -> Result<(), Error>;
+        /// Finish serializing the map.
+        fn end(self) -> Result<(), Error>;
+    }
+
+    /// Mockable counterpart of [SerializeStruct](::serde::ser::SerializeStruct).
+    #[unimock(prefix=crate, api=SerializeStructMock)]
+    pub trait SerializeStruct {
+        /// Serialize a struct field, before serializing the field value.
+        fn serialize_field(&mut self, key: &'static str) -> Result<(), Error>;
+        /// Indicate that a struct field has been skipped.
+        fn skip_field(&mut self, _key: &'static str) -> Result<(), Error> {
+            Ok(())
+        }
+        /// Finish serializing the struct.
+        fn end(self) -> Result<(), Error>;
+    }
+
+    /// Mockable counterpart of [SerializeStructVariant](::serde::ser::SerializeStructVariant).
+    #[unimock(prefix=crate, api=SerializeStructVariantMock)]
+    pub trait SerializeStructVariant {
+        /// Serialize a struct variant field, before serializing the field value.
+        fn serialize_field(&mut self, key: &'static str) -> Result<(), Error>;
+        /// Indicate that a struct variant field has been skipped.
+        fn skip_field(&mut self, _key: &'static str) -> Result<(), Error> {
+            Ok(())
+        }
+        /// Finish serializing the struct variant.
+        fn end(self) -> Result<(), Error>;
+    }
+
+    impl ::serde::Serializer for Unimock {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = Unimock;
+        type SerializeTuple = Unimock;
+        type SerializeTupleStruct = Unimock;
+        type SerializeTupleVariant = Unimock;
+        type SerializeMap = Unimock;
+        type SerializeStruct = Unimock;
+        type SerializeStructVariant = Unimock;
+
+        fn serialize_bool(self, v: bool) -> Result<(), Error> {
+            Serializer::serialize_bool(&self, v)
+        }
+
+        fn serialize_i8(self, v: i8) -> Result<(), Error> {
+            Serializer::serialize_i8(&self, v)
+        }
+
+        fn serialize_i16(self, v: i16) -> Result<(), Error> {
+            Serializer::serialize_i16(&self, v)
+        }
+
+        fn serialize_i32(self, v: i32) -> Result<(), Error> {
+            Serializer::serialize_i32(&self, v)
+        }
+
+        fn serialize_i64(self, v: i64) -> Result<(), Error> {
+            Serializer::serialize_i64(&self, v)
+        }
+
+        fn serialize_i128(self, v: i128) -> Result<(), Error> {
+            Serializer::serialize_i128(&self, v)
+        }
+
+        fn serialize_u8(self, v: u8) -> Result<(), Error> {
+            Serializer::serialize_u8(&self, v)
+        }
+
+        fn serialize_u16(self, v: u16) -> Result<(), Error> {
+            Serializer::serialize_u16(&self, v)
+        }
+
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            Serializer::serialize_u32(&self, v)
+        }
+
+        fn serialize_u64(self, v: u64) -> Result<(), Error> {
+            Serializer::serialize_u64(&self, v)
+        }
+
+        fn serialize_u128(self, v: u128) -> Result<(), Error> {
+            Serializer::serialize_u128(&self, v)
+        }
+
+        fn serialize_f32(self, v: f32) -> Result<(), Error> {
+            Serializer::serialize_f32(&self, v)
+        }
+
+        fn serialize_f64(self, v: f64) -> Result<(), Error> {
+            Serializer::serialize_f64(&self, v)
+        }
+
+        fn serialize_char(self, v: char) -> Result<(), Error> {
+            Serializer::serialize_char(&self, v)
+        }
+
+        fn serialize_str(self, v: &str) -> Result<(), Error> {
+            Serializer::serialize_str(&self, v)
+        }
+
+        fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+            Serializer::serialize_bytes(&self, v)
+        }
+
+        fn serialize_none(self) -> Result<(), Error> {
+            Serializer::serialize_none(&self)
+        }
+
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+            Serializer::serialize_some(&self)?;
+            value.serialize(self)
+        }
+
+        fn serialize_unit(self) -> Result<(), Error> {
+            Serializer::serialize_unit(&self)
+        }
+
+        fn serialize_unit_struct(self, name: &'static str) -> Result<(), Error> {
+            Serializer::serialize_unit_struct(&self, name)
+        }
+
+        fn serialize_unit_variant(
+            self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+        ) -> Result<(), Error> {
+            Serializer::serialize_unit_variant(&self, name, variant_index, variant)
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            name: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            Serializer::serialize_newtype_struct(&self, name)?;
+            value.serialize(self)
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            Serializer::serialize_newtype_variant(&self, name, variant_index, variant)?;
+            value.serialize(self)
+        }
+
+        fn serialize_seq(self, len: Option<usize>) -> Result<Unimock, Error> {
+            Serializer::serialize_seq(&self, len)?;
+            Ok(self)
+        }
+
+        fn serialize_tuple(self, len: usize) -> Result<Unimock, Error> {
+            Serializer::serialize_tuple(&self, len)?;
+            Ok(self)
+        }
+
+        fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<Unimock, Error> {
+            Serializer::serialize_tuple_struct(&self, name, len)?;
+            Ok(self)
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize,
+        ) -> Result<Unimock, Error> {
+            Serializer::serialize_tuple_variant(&self, name, variant_index, variant, len)?;
+            Ok(self)
+        }
+
+        fn serialize_map(self, len: Option<usize>) -> Result<Unimock, Error> {
+            Serializer::serialize_map(&self, len)?;
+            Ok(self)
+        }
+
+        fn serialize_struct(self, name: &'static str, len: usize) -> Result<Unimock, Error> {
+            Serializer::serialize_struct(&self, name, len)?;
+            Ok(self)
+        }
+
+        fn serialize_struct_variant(
+            self,
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize,
+        ) -> Result<Unimock, Error> {
+            Serializer::serialize_struct_variant(&self, name, variant_index, variant, len)?;
+            Ok(self)
+        }
+
+        fn is_human_readable(&self) -> bool {
+            Serializer::is_human_readable(self)
+        }
+    }
+
+    impl ::serde::ser::SerializeSeq for Unimock {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            SerializeSeq::serialize_element(self)?;
+            value.serialize(self.clone())
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeSeq::end(self)
+        }
+    }
+
+    impl ::serde::ser::SerializeTuple for Unimock {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            SerializeTuple::serialize_element(self)?;
+            value.serialize(self.clone())
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeTuple::end(self)
+        }
+    }
+
+    impl ::serde::ser::SerializeTupleStruct for Unimock {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            SerializeTupleStruct::serialize_field(self)?;
+            value.serialize(self.clone())
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeTupleStruct::end(self)
+        }
+    }
+
+    impl ::serde::ser::SerializeTupleVariant for Unimock {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            SerializeTupleVariant::serialize_field(self)?;
+            value.serialize(self.clone())
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeTupleVariant::end(self)
+        }
+    }
+
+    impl ::serde::ser::SerializeMap for Unimock {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+            SerializeMap::serialize_key(self)?;
+            key.serialize(self.clone())
+        }
+
+        fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            SerializeMap::serialize_value(self)?;
+            value.serialize(self.clone())
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeMap::end(self)
+        }
+    }
+
+    impl ::serde::ser::SerializeStruct for Unimock {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            SerializeStruct::serialize_field(self, key)?;
+            value.serialize(self.clone())
+        }
+
+        fn skip_field(&mut self, key: &'static str) -> Result<(), Error> {
+            SerializeStruct::skip_field(self, key)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeStruct::end(self)
+        }
+    }
+
+    impl ::serde::ser::SerializeStructVariant for Unimock {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            SerializeStructVariant::serialize_field(self, key)?;
+            value.serialize(self.clone())
+        }
+
+        fn skip_field(&mut self, key: &'static str) -> Result<(), Error> {
+            SerializeStructVariant::skip_field(self, key)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeStructVariant::end(self)
+        }
+    }
+}
+
+/// Mock APIs for `serde::de` traits
+///
+/// Every mocked `deserialize_*` method responds with a [Visit](de::Visit),
+/// which selects the [Visitor](::serde::de::Visitor) method to call.
+/// Compound values, e.g. [Visit::Map](de::Visit::Map), continue with the `Unimock` as [MapAccess](::serde::de::MapAccess),
+/// so keys and values are deserialized through further mocked calls.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use unimock::*;
+/// use unimock::mock::serde::de::{DeserializerMock, MapAccessMock, Visit};
+///
+/// #[derive(Deserialize, PartialEq, Debug)]
+/// struct Meters {
+///     value: u32,
+/// }
+///
+/// let u = Unimock::new((
+///     DeserializerMock::deserialize_struct
+///         .next_call(matching!("Meters", ["value"]))
+///         .returns(Ok(Visit::Map)),
+///     MapAccessMock::next_key.next_call(matching!()).returns(Ok(true)),
+///     DeserializerMock::deserialize_identifier
+///         .next_call(matching!())
+///         .returns(Ok(Visit::Str("value".to_string()))),
+///     DeserializerMock::deserialize_u32
+///         .next_call(matching!())
+///         .returns(Ok(Visit::U64(42))),
+///     MapAccessMock::next_key.next_call(matching!()).returns(Ok(false)),
+/// ));
+///
+/// assert_eq!(Meters { value: 42 }, Meters::deserialize(u).unwrap());
+/// ```
+#[cfg(feature = "mock-serde")]
+pub mod de {
+    use ::serde::de::{DeserializeSeed, Visitor};
+    use unimock_macros::unimock;
+
+    use super::Error;
+    use crate::private::lib::{String, Vec};
+    use crate::Unimock;
+
+    /// The [Visitor] method a mocked `deserialize_*` call continues with.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Visit {
+        /// Call `visit_bool`.
+        Bool(bool),
+        /// Call `visit_i64`.
+        I64(i64),
+        /// Call `visit_i128`.
+        I128(i128),
+        /// Call `visit_u64`.
+        U64(u64),
+        /// Call `visit_u128`.
+        U128(u128),
+        /// Call `visit_f64`.
+        F64(f64),
+        /// Call `visit_char`.
+        Char(char),
+        /// Call `visit_string`.
+        Str(String),
+        /// Call `visit_byte_buf`.
+        Bytes(Vec<u8>),
+        /// Call `visit_none`.
+        None,
+        /// Call `visit_some`, deserializing the inner value through the mock.
+        Some,
+        /// Call `visit_unit`.
+        Unit,
+        /// Call `visit_newtype_struct`, deserializing the inner value through the mock.
+        NewtypeStruct,
+        /// Call `visit_seq`, using the `Unimock` as [SeqAccess](::serde::de::SeqAccess).
+        Seq,
+        /// Call `visit_map`, using the `Unimock` as [MapAccess](::serde::de::MapAccess).
+        Map,
+        /// Call `visit_enum`, using the `Unimock` as [EnumAccess](::serde::de::EnumAccess).
+        ///
+        /// The variant is deserialized through the mock, e.g. as an identifier.
+        Enum,
+    }
+
+    /// Mockable counterpart of [Deserializer](::serde::Deserializer).
+    #[unimock(prefix=crate, api=DeserializerMock)]
+    pub trait Deserializer {
+        /// Deserialize a self-describing value.
+        fn deserialize_any(&self) -> Result<Visit, Error>;
+        /// Deserialize a `bool`.
+        fn deserialize_bool(&self) -> Result<Visit, Error>;
+        /// Deserialize an `i8`.
+        fn deserialize_i8(&self) -> Result<Visit, Error>;
+        /// Deserialize an `i16`.
+        fn deserialize_i16(&self) -> Result<Visit, Error>;
+        /// Deserialize an `i32`.
+        fn deserialize_i32(&self) -> Result<Visit, Error>;
+        /// Deserialize an `i64`.
+        fn deserialize_i64(&self) -> Result<Visit, Error>;
+        /// Deserialize an `i128`.
+        fn deserialize_i128(&self) -> Result<Visit, Error>;
+        /// Deserialize a `u8`.
+        fn deserialize_u8(&self) -> Result<Visit, Error>;
+        /// Deserialize a `u16`.
+        fn deserialize_u16(&self) -> Result<Visit, Error>;
+        /// Deserialize a `u32`.
+        fn deserialize_u32(&self) -> Result<Visit, Error>;
+        /// Deserialize a `u64`.
+        fn deserialize_u64(&self) -> Result<Visit, Error>;
+        /// Deserialize a `u128`.
+        fn deserialize_u128(&self) -> Result<Visit, Error>;
+        /// Deserialize an `f32`.
+        fn deserialize_f32(&self) -> Result<Visit, Error>;
+        /// Deserialize an `f64`.
+        fn deserialize_f64(&self) -> Result<Visit, Error>;
+        /// Deserialize a `char`.
+        fn deserialize_char(&self) -> Result<Visit, Error>;
+        /// Deserialize a borrowed string.
+        fn deserialize_str(&self) -> Result<Visit, Error>;
+        /// Deserialize an owned `String`.
+        fn deserialize_string(&self) -> Result<Visit, Error>;
+        /// Deserialize borrowed bytes.
+        fn deserialize_bytes(&self) -> Result<Visit, Error>;
+        /// Deserialize an owned byte buffer.
+        fn deserialize_byte_buf(&self) -> Result<Visit, Error>;
+        /// Deserialize an `Option`.
+        fn deserialize_option(&self) -> Result<Visit, Error>;
+        /// Deserialize a `()` value.
+        fn deserialize_unit(&self) -> Result<Visit, Error>;
+        /// Deserialize a unit struct with the given name.
+        fn deserialize_unit_struct(&self, name: &'static str) -> Result<Visit, Error>;
+        /// Deserialize a newtype struct with the given name.
+        fn deserialize_newtype_struct(&self, name: &'static str) -> Result<Visit, Error>;
+        /// Deserialize a sequence.
+        fn deserialize_seq(&self) -> Result<Visit, Error>;
+        /// Deserialize a tuple of the given length.
+        fn deserialize_tuple(&self, len: usize) -> Result<Visit, Error>;
+        /// Deserialize a tuple struct with the given name and length.
+        fn deserialize_tuple_struct(&self, name: &'static str, len: usize) -> Result<Visit, Error>;
+        /// Deserialize a map.
+        fn deserialize_map(&self) -> Result<Visit, Error>;
+        /// Deserialize a struct with the given name and fields.
+        fn deserialize_struct(
+            &self,
+            name: &'static str,
+            fields: &'static [&'static str],
+        ) -> Result<Visit, Error>;
+        /// Deserialize an enum with the given name and variants.
+        fn deserialize_enum(
+            &self,
+            name: &'static str,
+            variants: &'static [&'static str],
+        ) -> Result<Visit, Error>;
+        /// Deserialize a struct field or enum variant identifier.
+        fn deserialize_identifier(&self) -> Result<Visit, Error>;
+        /// Deserialize a value that is going to be ignored.
+        fn deserialize_ignored_any(&self) -> Result<Visit, Error>;
+        /// Whether `Deserialize` implementations should expect human-readable form.
+        fn is_human_readable(&self) -> bool {
+            true
+        }
+    }
+
+    /// Mockable counterpart of [SeqAccess](::serde::de::SeqAccess).
+    #[unimock(prefix=crate, api=SeqAccessMock)]
+    pub trait SeqAccess {
+        /// Whether there is a next element, which is then deserialized through the mock.
+        fn next_element(&mut self) -> Result<bool, Error>;
+        /// The number of remaining elements, if known.
+        fn size_hint(&self) -> Option<usize> {
+            None
+        }
+    }
+
+    /// Mockable counterpart of [MapAccess](::serde::de::MapAccess).
+    #[unimock(prefix=crate, api=MapAccessMock)]
+    pub trait MapAccess {
+        /// Whether there is a next entry, whose key is then deserialized through the mock.
+        fn next_key(&mut self) -> Result<bool, Error>;
+        /// Deserialize the value of the entry through the mock.
+        fn next_value(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+        /// The number of remaining entries, if known.
+        fn size_hint(&self) -> Option<usize> {
+            None
+        }
+    }
+
+    /// Mockable counterpart of [VariantAccess](::serde::de::VariantAccess).
+    ///
+    /// The contents of newtype, tuple and struct variants are deserialized through the mock afterwards.
+    /// Tuple and struct variants continue with [DeserializerMock::deserialize_seq] and [DeserializerMock::deserialize_map] respectively.
+    #[unimock(prefix=crate, api=VariantAccessMock)]
+    pub trait VariantAccess {
+        /// The variant is a unit variant.
+        fn unit_variant(&self) -> Result<(), Error> {
+            Ok(())
+        }
+        /// The variant is a newtype variant.
+        fn newtype_variant(&self) -> Result<(), Error> {
+            Ok(())
+        }
+        /// The variant is a tuple variant of the given length.
+        fn tuple_variant(&self, _len: usize) -> Result<(), Error> {
+            Ok(())
+        }
+        /// The variant is a struct variant with the given fields.
+        fn struct_variant(&self, _fields: &'static [&'static str]) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    fn visit<'de, V: Visitor<'de>>(
+        unimock: Unimock,
+        visit: Visit,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match visit {
+            Visit::Bool(v) => visitor.visit_bool(v),
+            Visit::I64(v) => visitor.visit_i64(v),
+            Visit::I128(v) => visitor.visit_i128(v),
+            Visit::U64(v) => visitor.visit_u64(v),
+            Visit::U128(v) => visitor.visit_u128(v),
+            Visit::F64(v) => visitor.visit_f64(v),
+            Visit::Char(v) => visitor.visit_char(v),
+            Visit::Str(v) => visitor.visit_string(v),
+            Visit::Bytes(v) => visitor.visit_byte_buf(v),
+            Visit::None => visitor.visit_none(),
+            Visit::Some => visitor.visit_some(unimock),
+            Visit::Unit => visitor.visit_unit(),
+            Visit::NewtypeStruct => visitor.visit_newtype_struct(unimock),
+            Visit::Seq => visitor.visit_seq(unimock),
+            Visit::Map => visitor.visit_map(unimock),
+            Visit::Enum => visitor.visit_enum(unimock),
+        }
+    }
+
+    macro_rules! deserialize_methods {
+        ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+            $(
+                fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Error> {
+                    let visit_with = Deserializer::$method(&self, $($arg),*)?;
+                    visit(self, visit_with, visitor)
+                }
+            )*
+        };
+    }
+
+    impl<'de> ::serde::Deserializer<'de> for Unimock {
+        type Error = Error;
+
+        deserialize_methods! {
+            deserialize_any();
+            deserialize_bool();
+            deserialize_i8();
+            deserialize_i16();
+            deserialize_i32();
+            deserialize_i64();
+            deserialize_i128();
+            deserialize_u8();
+            deserialize_u16();
+            deserialize_u32();
+            deserialize_u64();
+            deserialize_u128();
+            deserialize_f32();
+            deserialize_f64();
+            deserialize_char();
+            deserialize_str();
+            deserialize_string();
+            deserialize_bytes();
+            deserialize_byte_buf();
+            deserialize_option();
+            deserialize_unit();
+            deserialize_unit_struct(name: &'static str);
+            deserialize_newtype_struct(name: &'static str);
+            deserialize_seq();
+            deserialize_tuple(len: usize);
+            deserialize_tuple_struct(name: &'static str, len: usize);
+            deserialize_map();
+            deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+            deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+            deserialize_identifier();
+            deserialize_ignored_any();
+        }
+
+        fn is_human_readable(&self) -> bool {
+            Deserializer::is_human_readable(self)
+        }
+    }
+
+    impl<'de> ::serde::de::SeqAccess<'de> for Unimock {
+        type Error = Error;
+
+        fn next_element_seed<T: DeserializeSeed<'de>>(
+            &mut self,
+            seed: T,
+        ) -> Result<Option<T::Value>, Error> {
+            if SeqAccess::next_element(self)? {
+                seed.deserialize(self.clone()).map(Some)
+            } else {
+                Ok(None)
+            }
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            SeqAccess::size_hint(self)
+        }
+    }
+
+    impl<'de> ::serde::de::MapAccess<'de> for Unimock {
+        type Error = Error;
+
+        fn next_key_seed<K: DeserializeSeed<'de>>(
+            &mut self,
+            seed: K,
+        ) -> Result<Option<K::Value>, Error> {
+            if MapAccess::next_key(self)? {
+                seed.deserialize(self.clone()).map(Some)
+            } else {
+                Ok(None)
+            }
+        }
+
+        fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+            MapAccess::next_value(self)?;
+            seed.deserialize(self.clone())
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            MapAccess::size_hint(self)
+        }
+    }
+
+    impl<'de> ::serde::de::EnumAccess<'de> for Unimock {
+        type Error = Error;
+        type Variant = Unimock;
+
+        fn variant_seed<V: DeserializeSeed<'de>>(
+            self,
+            seed: V,
+        ) -> Result<(V::Value, Unimock), Error> {
+            let variant = seed.deserialize(self.clone())?;
+            Ok((variant, self))
+        }
+    }
+
+    impl<'de> ::serde::de::VariantAccess<'de> for Unimock {
+        type Error = Error;
+
+        fn unit_variant(self) -> Result<(), Error> {
+            VariantAccess::unit_variant(&self)
+        }
+
+        fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+            VariantAccess::newtype_variant(&self)?;
+            seed.deserialize(self)
+        }
+
+        fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+            VariantAccess::tuple_variant(&self, len)?;
+            ::serde::Deserializer::deserialize_seq(self, visitor)
+        }
+
+        fn struct_variant<V: Visitor<'de>>(
+            self,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            VariantAccess::struct_variant(&self, fields)?;
+            ::serde::Deserializer::deserialize_map(self, visitor)
+        }
+    }
+}
@@ -3,7 +3,11 @@
 /// Mock APIs for `std::error` traits
 #[cfg(feature = "mock-std")]
 pub mod error {
-    use crate::unimock;
+    use crate::mock::core::fmt::DisplayMock;
+    use crate::private::lib::{Arc, String, ToString, Vec};
+    use crate::{clause, matching, unimock, Clause, MockFn};
+    use core::borrow::Borrow;
+    use core::fmt::Display;
     use std::error::Error;
 
     #[unimock(prefix=crate, api=ErrorMock, mirror=std::error::Error)]
@@ -17,13 +21,263 @@ pub mod error {
         // Note: Unstable
         // fn provide<'a>(&'a self, demand: &mut Demand<'a>) {}
     }
+
+    impl ErrorMock::source {
+        /// Mock `source` to return a chain of errors with the given messages, outermost first.
+        ///
+        /// The returned clause can also mock the `Display` message of the error itself,
+        /// see [SourceChain::with_message].
+        ///
+        /// Like any other clause, it fails verification with a "never called" error
+        /// unless `source` is called at least once.
+        ///
+        /// # Example
+        /// ```rust
+        /// use unimock::*;
+        /// use unimock::mock::std::error::ErrorMock;
+        /// use std::error::Error;
+        ///
+        /// let error = Unimock::new(
+        ///     ErrorMock::source
+        ///         .returns_chain(["connection reset", "broken pipe"])
+        ///         .with_message("request failed"),
+        /// );
+        ///
+        /// let mut messages = vec![error.to_string()];
+        /// let mut source = error.source();
+        /// while let Some(error) = source {
+        ///     messages.push(error.to_string());
+        ///     source = error.source();
+        /// }
+        ///
+        /// assert_eq!(vec!["request failed", "connection reset", "broken pipe"], messages);
+        /// ```
+        pub fn returns_chain<M: Display>(
+            self,
+            messages: impl IntoIterator<Item = M>,
+        ) -> SourceChain {
+            let messages: Vec<String> = messages.into_iter().map(|m| m.to_string()).collect();
+            let source = messages.into_iter().rev().fold(None, |source, message| {
+                Some(ChainedError {
+                    message,
+                    source: source.map(Arc::new),
+                })
+            });
+
+            SourceChain {
+                source,
+                message: None,
+            }
+        }
+    }
+
+    /// A [Clause] mocking the [source](std::error::Error::source) chain of an error, created by [`ErrorMock::source.returns_chain`](ErrorMock::source::returns_chain).
+    pub struct SourceChain {
+        source: Option<ChainedError>,
+        message: Option<String>,
+    }
+
+    impl SourceChain {
+        /// Also mock the `Display` implementation of the error itself, writing the given message.
+        ///
+        /// The error then has to be displayed at least once, or verification fails with a "never called" error.
+        pub fn with_message(self, message: impl Display) -> Self {
+            Self {
+                message: Some(message.to_string()),
+                ..self
+            }
+        }
+    }
+
+    impl Clause for SourceChain {
+        fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), String> {
+            ErrorMock::source
+                .each_call(matching!())
+                .returns(self.source)
+                .deconstruct(sink)?;
+
+            if let Some(message) = self.message {
+                DisplayMock::fmt
+                    .each_call(matching!())
+                    .mutates(move |f, _| f.write_str(&message))
+                    .deconstruct(sink)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// An error in a chain of errors returned from a mocked [source](std::error::Error::source).
+    #[derive(Clone, Debug)]
+    pub struct ChainedError {
+        message: String,
+        source: Option<Arc<ChainedError>>,
+    }
+
+    impl Display for ChainedError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.message)
+        }
+    }
+
+    impl Error for ChainedError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.source
+                .as_deref()
+                .map(|source| source as &(dyn Error + 'static))
+        }
+    }
+
+    impl Borrow<dyn Error + 'static> for ChainedError {
+        fn borrow(&self) -> &(dyn Error + 'static) {
+            self
+        }
+    }
+}
+
+/// Mock APIs for `std::fs`-style file system access
+///
+/// `std::fs` consists of free functions, which can't be mocked.
+/// [FileSystem](fs::FileSystem) is a trait with the same shape, implemented for [StdFileSystem](fs::StdFileSystem) in release code.
+/// The mocked `Unimock` implementation falls back to `std::fs` in partial mocks:
+///
+/// ```rust
+/// use unimock::*;
+/// use unimock::mock::std::fs::{FileSystem, FileSystemMock};
+/// use std::path::Path;
+///
+/// fn config(fs: &impl FileSystem) -> String {
+///     fs.read_to_string(Path::new("app.toml")).unwrap_or_default()
+/// }
+///
+/// let fs = Unimock::new_partial(
+///     FileSystemMock::read_to_string
+///         .next_call(matching!((path) if *path == Path::new("app.toml")))
+///         .returns(Ok("debug = true".to_string())),
+/// );
+/// assert_eq!("debug = true", config(&fs));
+/// ```
+#[cfg(feature = "mock-std")]
+pub mod fs {
+    use std::io::Result;
+    use std::path::{Path, PathBuf};
+
+    use crate::{unimock, Unimock};
+
+    /// File system access, mirroring functions in [std::fs].
+    #[unimock(prefix=crate, api=FileSystemMock, unmock_with=[read, read_to_string, write, exists, create_dir_all, remove_file, read_dir])]
+    pub trait FileSystem {
+        /// See [std::fs::read].
+        fn read(&self, path: &Path) -> Result<Vec<u8>>;
+        /// See [std::fs::read_to_string].
+        fn read_to_string(&self, path: &Path) -> Result<String>;
+        /// See [std::fs::write].
+        fn write(&self, path: &Path, contents: &[u8]) -> Result<()>;
+        /// See [Path::exists].
+        fn exists(&self, path: &Path) -> bool;
+        /// See [std::fs::create_dir_all].
+        fn create_dir_all(&self, path: &Path) -> Result<()>;
+        /// See [std::fs::remove_file].
+        fn remove_file(&self, path: &Path) -> Result<()>;
+        /// The paths of the entries in a directory, see [std::fs::read_dir].
+        fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>>;
+    }
+
+    /// The real [FileSystem], using [std::fs].
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct StdFileSystem;
+
+    impl FileSystem for StdFileSystem {
+        fn read(&self, path: &Path) -> Result<Vec<u8>> {
+            std::fs::read(path)
+        }
+
+        fn read_to_string(&self, path: &Path) -> Result<String> {
+            std::fs::read_to_string(path)
+        }
+
+        fn write(&self, path: &Path, contents: &[u8]) -> Result<()> {
+            std::fs::write(path, contents)
+        }
+
+        fn exists(&self, path: &Path) -> bool {
+            path.exists()
+        }
+
+        fn create_dir_all(&self, path: &Path) -> Result<()> {
+            std::fs::create_dir_all(path)
+        }
+
+        fn remove_file(&self, path: &Path) -> Result<()> {
+            std::fs::remove_file(path)
+        }
+
+        fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+            std::fs::read_dir(path)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect()
+        }
+    }
+
+    fn read(_: &Unimock, path: &Path) -> Result<Vec<u8>> {
+        StdFileSystem.read(path)
+    }
+
+    fn read_to_string(_: &Unimock, path: &Path) -> Result<String> {
+        StdFileSystem.read_to_string(path)
+    }
+
+    fn write(_: &Unimock, path: &Path, contents: &[u8]) -> Result<()> {
+        StdFileSystem.write(path, contents)
+    }
+
+    fn exists(_: &Unimock, path: &Path) -> bool {
+        StdFileSystem.exists(path)
+    }
+
+    fn create_dir_all(_: &Unimock, path: &Path) -> Result<()> {
+        StdFileSystem.create_dir_all(path)
+    }
+
+    fn remove_file(_: &Unimock, path: &Path) -> Result<()> {
+        StdFileSystem.remove_file(path)
+    }
+
+    fn read_dir(_: &Unimock, path: &Path) -> Result<Vec<PathBuf>> {
+        StdFileSystem.read_dir(path)
+    }
+}
+
+/// Mock APIs for `std::hash` traits
+#[cfg(feature = "mock-std")]
+pub mod hash {
+    use crate::{unimock, Unimock};
+
+    /// The built hashers are `Unimock` instances, which can be mocked through [HasherMock](crate::mock::core::hash::HasherMock).
+    /// Use e.g. [`answers_ctx`](crate::build::DefineResponse::answers_ctx) and [`clone_instance`](crate::build::AnswerContext::clone_instance)
+    /// to build hashers that share the mock state of the builder.
+    #[unimock(prefix=crate, api=BuildHasherMock, mirror=std::hash::BuildHasher, type Hasher = Unimock;)]
+    pub trait BuildHasher {
+        type Hasher;
+
+        fn build_hasher(&self) -> Self::Hasher;
+    }
 }
 
 /// Mock APIs for `std::io` traits
+///
+/// Besides the trait mocks, this module contains scripted responders that implement the I/O contracts on behalf of the test.
+/// A [ReadScript](io::ReadScript) plays back chunks of bytes, errors and end-of-file markers through [ReadMock](io::ReadMock), [BufReadMock](io::BufReadMock) and [SeekMock](io::SeekMock),
+/// and a [WriteScript](io::WriteScript) records the bytes written through [WriteMock](io::WriteMock).
 #[cfg(feature = "mock-std")]
 pub mod io {
-    use std::io::{IoSlice, IoSliceMut, Result, SeekFrom};
+    use core::cmp::min;
+    use std::collections::VecDeque;
+    use std::io::{Error, ErrorKind, IoSlice, IoSliceMut, Result, SeekFrom};
+    use std::sync::{Mutex, MutexGuard};
 
+    use crate::private::lib::{Arc, String, Vec};
+    use crate::{clause, matching, Clause, MockFn, Unimock};
     use unimock_macros::unimock;
 
     #[unimock(prefix=crate, api=BufReadMock, mirror=std::io::BufRead)]
@@ -56,49 +310,641 @@ pub mod io {
         // fn stream_len(&mut self) -> Result<u64> {}
     }
 
-    #[unimock(prefix=crate, api=WriteMock, mirror=std::io::Write)]
+    #[unimock(prefix=crate, api=WriteMock, mirror=std::io::Write, unmock_with=[_, _, _, _, write_fmt_unmocked], partial_by_default=true)]
     pub trait Write {
         fn write(&mut self, buf: &[u8]) -> Result<usize>;
         fn flush(&mut self) -> Result<()>;
         fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {}
         fn write_all(&mut self, buf: &[u8]) -> Result<()> {}
+        fn write_fmt(&mut self, fmt: std::fmt::Arguments<'_>) -> Result<()> {}
+    }
+
+    // Like the default implementation of `write_fmt`, but writes through the (possibly mocked) `write_all` of the instance.
+    fn write_fmt_unmocked(unimock: &mut Unimock, fmt: std::fmt::Arguments<'_>) -> Result<()> {
+        struct Adapter<'u> {
+            unimock: &'u mut Unimock,
+            result: Result<()>,
+        }
+
+        impl core::fmt::Write for Adapter<'_> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                match std::io::Write::write_all(self.unimock, s.as_bytes()) {
+                    Ok(()) => Ok(()),
+                    Err(error) => {
+                        self.result = Err(error);
+                        Err(core::fmt::Error)
+                    }
+                }
+            }
+        }
 
-        // FIXME: This is not implemented (yet) because of self-lifetime in argument.
-        // It just uses the default implementation which delegates to `Self::write`.
-        // fn write_fmt(&mut self, fmt: std::fmt::Arguments<'_>) -> std::io::Result<()> {}
+        let mut adapter = Adapter {
+            unimock,
+            result: Ok(()),
+        };
+        match core::fmt::write(&mut adapter, fmt) {
+            Ok(()) => Ok(()),
+            Err(_) if adapter.result.is_err() => adapter.result,
+            Err(_) => Err(Error::new(ErrorKind::Other, "formatter error")),
+        }
+    }
+
+    /// A script of bytes, errors and end-of-file markers played back by mocked readers.
+    ///
+    /// The script is shared by all the clauses created from it, so a reader mocked through several traits,
+    /// e.g. [`ReadMock::read`](ReadMock::read::reads) and [`SeekMock::seek`](SeekMock::seek::seeks), moves through one stream.
+    ///
+    /// The contracts of the reading traits are upheld by the script:
+    /// * A read never returns more bytes than fit in the buffer, and never reads across two chunks.
+    /// * An error is returned once, and then the script moves on. This models [ErrorKind::Interrupted] and [ErrorKind::WouldBlock].
+    /// * An [eof](ReadScript::eof) marker reads as `Ok(0)` once. The end of the script reads as `Ok(0)` on every call.
+    /// * A seek beyond the end of the chunks returns the requested position, and skips the remaining steps.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{BufRead, ErrorKind};
+    /// use unimock::*;
+    /// use unimock::mock::std::io::{BufReadMock, ReadScript};
+    ///
+    /// let script = ReadScript::new()
+    ///     .chunk("hello ")
+    ///     .error(ErrorKind::Interrupted)
+    ///     .chunk("world\nbye\n");
+    ///
+    /// let mut reader = Unimock::new(BufReadMock::fill_buf.reads(&script));
+    /// let lines: Vec<String> = (&mut reader).lines().map(Result::unwrap).collect();
+    ///
+    /// assert_eq!(vec!["hello world", "bye"], lines);
+    /// assert!(script.is_finished());
+    /// ```
+    #[derive(Clone, Debug, Default)]
+    pub struct ReadScript {
+        cursor: Arc<Mutex<ReadCursor>>,
+    }
+
+    impl ReadScript {
+        /// Create an empty script, which reads as end-of-file.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Append a chunk of bytes to the script.
+        pub fn chunk(self, bytes: impl AsRef<[u8]>) -> Self {
+            self.push(ReadStep::Chunk(bytes.as_ref().to_vec()))
+        }
+
+        /// Append an error of the given kind to the script.
+        pub fn error(self, kind: ErrorKind) -> Self {
+            self.push(ReadStep::Error(kind))
+        }
+
+        /// Append an end-of-file marker to the script.
+        ///
+        /// Reads continue after the marker, like a terminal where the user pressed `Ctrl-D`.
+        pub fn eof(self) -> Self {
+            self.push(ReadStep::Eof)
+        }
+
+        /// Whether the whole script has been played back.
+        pub fn is_finished(&self) -> bool {
+            let mut cursor = self.lock();
+            cursor.skip_consumed();
+            cursor.index >= cursor.steps.len()
+        }
+
+        fn push(self, step: ReadStep) -> Self {
+            self.lock().steps.push(step);
+            self
+        }
+
+        fn lock(&self) -> MutexGuard<'_, ReadCursor> {
+            lock(&self.cursor)
+        }
+    }
+
+    #[derive(Debug)]
+    enum ReadStep {
+        Chunk(Vec<u8>),
+        Error(ErrorKind),
+        Eof,
+    }
+
+    #[derive(Debug, Default)]
+    struct ReadCursor {
+        steps: Vec<ReadStep>,
+        index: usize,
+        offset: usize,
+        // The distance of a seek beyond the end of the chunks.
+        beyond_end: u64,
+    }
+
+    impl ReadCursor {
+        fn skip_consumed(&mut self) {
+            while let Some(ReadStep::Chunk(chunk)) = self.steps.get(self.index) {
+                if self.offset < chunk.len() {
+                    break;
+                }
+                self.index += 1;
+                self.offset = 0;
+            }
+        }
+
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            self.skip_consumed();
+            match self.steps.get(self.index) {
+                Some(ReadStep::Chunk(chunk)) => Ok(&chunk[self.offset..]),
+                Some(ReadStep::Error(kind)) => {
+                    self.index += 1;
+                    Err(Error::from(*kind))
+                }
+                Some(ReadStep::Eof) => {
+                    self.index += 1;
+                    Ok(&[])
+                }
+                None => Ok(&[]),
+            }
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.skip_consumed();
+            if let Some(ReadStep::Chunk(chunk)) = self.steps.get(self.index) {
+                self.offset = min(self.offset + amt, chunk.len());
+            }
+        }
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            let available = self.fill_buf()?;
+            let len = min(available.len(), buf.len());
+            buf[..len].copy_from_slice(&available[..len]);
+            self.consume(len);
+            Ok(len)
+        }
+
+        fn chunks(&self) -> impl Iterator<Item = (usize, &[u8])> {
+            self.steps
+                .iter()
+                .enumerate()
+                .filter_map(|(index, step)| match step {
+                    ReadStep::Chunk(chunk) => Some((index, chunk.as_slice())),
+                    _ => None,
+                })
+        }
+
+        fn position(&self) -> u64 {
+            let consumed: usize = self
+                .chunks()
+                .take_while(|(index, _)| *index < self.index)
+                .map(|(_, chunk)| chunk.len())
+                .sum();
+            (consumed + self.offset) as u64 + self.beyond_end
+        }
+
+        fn len(&self) -> u64 {
+            self.chunks().map(|(_, chunk)| chunk.len() as u64).sum()
+        }
+
+        /// Seek to a byte position within the chunks.
+        /// Steps between the previous chunk and the chunk at the new position are played back again.
+        /// Seeking beyond the end of the chunks is allowed, and the following reads return end-of-file.
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+            let target = match pos {
+                SeekFrom::Start(target) => Some(target),
+                SeekFrom::End(delta) => offset(self.len(), delta),
+                SeekFrom::Current(delta) => offset(self.position(), delta),
+            }
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "invalid seek position"))?;
+
+            let mut start = 0;
+            let mut landing = (0, 0);
+            for (index, chunk) in self.chunks() {
+                let end = start + chunk.len() as u64;
+                if target < end {
+                    landing = (index, (target - start) as usize);
+                    start = target;
+                    break;
+                }
+                start = end;
+                landing = (index + 1, 0);
+            }
+
+            if target > start {
+                landing = (self.steps.len(), 0);
+            }
+
+            (self.index, self.offset) = landing;
+            self.beyond_end = target - start;
+            Ok(target)
+        }
+    }
+
+    /// A script of accepted byte counts and errors played back by a mocked writer, recording the bytes written.
+    ///
+    /// By default, every write is accepted in full.
+    /// Steps appended to the script are applied to one write each, in order:
+    /// * [accept](WriteScript::accept) limits a write to a number of bytes, modelling a short write.
+    ///   Accepting `0` bytes models a closed stream.
+    /// * [error](WriteScript::error) fails a write, e.g. with [ErrorKind::Interrupted] or [ErrorKind::WouldBlock].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{ErrorKind, Write};
+    /// use unimock::*;
+    /// use unimock::mock::std::io::{WriteMock, WriteScript};
+    ///
+    /// let script = WriteScript::new().accept(3).error(ErrorKind::Interrupted);
+    /// let mut writer = Unimock::new(WriteMock::write.writes(&script));
+    ///
+    /// write!(&mut writer, "hello {}", "world").unwrap();
+    /// assert_eq!(b"hello world", script.written().as_slice());
+    /// ```
+    #[derive(Clone, Debug, Default)]
+    pub struct WriteScript {
+        cursor: Arc<Mutex<WriteCursor>>,
+    }
+
+    impl WriteScript {
+        /// Create an empty script, which accepts every write in full.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Let the next write accept at most `len` bytes.
+        pub fn accept(self, len: usize) -> Self {
+            self.push(WriteStep::Accept(len))
+        }
+
+        /// Fail the next write with an error of the given kind.
+        pub fn error(self, kind: ErrorKind) -> Self {
+            self.push(WriteStep::Error(kind))
+        }
+
+        /// The bytes written so far.
+        pub fn written(&self) -> Vec<u8> {
+            self.lock().written.clone()
+        }
+
+        /// Whether every step of the script has been played back.
+        pub fn is_finished(&self) -> bool {
+            self.lock().steps.is_empty()
+        }
+
+        fn push(self, step: WriteStep) -> Self {
+            self.lock().steps.push_back(step);
+            self
+        }
+
+        fn lock(&self) -> MutexGuard<'_, WriteCursor> {
+            lock(&self.cursor)
+        }
+    }
+
+    #[derive(Debug)]
+    enum WriteStep {
+        Accept(usize),
+        Error(ErrorKind),
+    }
+
+    #[derive(Debug, Default)]
+    struct WriteCursor {
+        steps: VecDeque<WriteStep>,
+        written: Vec<u8>,
+    }
+
+    impl WriteCursor {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            let len = match self.steps.pop_front() {
+                Some(WriteStep::Accept(len)) => min(len, buf.len()),
+                Some(WriteStep::Error(kind)) => return Err(Error::from(kind)),
+                None => buf.len(),
+            };
+            self.written.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+    }
+
+    // `u64::checked_add_signed` requires Rust 1.66.
+    fn offset(position: u64, delta: i64) -> Option<u64> {
+        if delta < 0 {
+            position.checked_sub(delta.unsigned_abs())
+        } else {
+            position.checked_add(delta as u64)
+        }
+    }
+
+    fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    impl ReadMock::read {
+        /// Mock `read` to play back the given script.
+        pub fn reads(self, script: &ReadScript) -> Scripted {
+            Scripted(ScriptedFn::Read(script.cursor.clone()))
+        }
+    }
+
+    impl BufReadMock::fill_buf {
+        /// Mock `fill_buf` and `consume` to play back the given script.
+        ///
+        /// `fill_buf` returns the unconsumed rest of the current chunk, and `consume` advances within it.
+        pub fn reads(self, script: &ReadScript) -> Scripted {
+            Scripted(ScriptedFn::BufRead(script.cursor.clone()))
+        }
+    }
+
+    impl SeekMock::seek {
+        /// Mock `seek` to move within the chunks of the given script.
+        pub fn seeks(self, script: &ReadScript) -> Scripted {
+            Scripted(ScriptedFn::Seek(script.cursor.clone()))
+        }
+    }
+
+    impl WriteMock::write {
+        /// Mock `write` to play back the given script.
+        pub fn writes(self, script: &WriteScript) -> Scripted {
+            Scripted(ScriptedFn::Write(script.cursor.clone()))
+        }
+    }
+
+    /// A [Clause] playing back a [ReadScript] or [WriteScript], created by
+    /// [`ReadMock::read.reads`](ReadMock::read::reads), [`BufReadMock::fill_buf.reads`](BufReadMock::fill_buf::reads),
+    /// [`SeekMock::seek.seeks`](SeekMock::seek::seeks) or [`WriteMock::write.writes`](WriteMock::write::writes).
+    ///
+    /// Like other `each_call` clauses, every mocked function must be called at least once for the mock to verify.
+    pub struct Scripted(ScriptedFn);
+
+    enum ScriptedFn {
+        Read(Arc<Mutex<ReadCursor>>),
+        BufRead(Arc<Mutex<ReadCursor>>),
+        Seek(Arc<Mutex<ReadCursor>>),
+        Write(Arc<Mutex<WriteCursor>>),
+    }
+
+    impl Clause for Scripted {
+        fn deconstruct(
+            self,
+            sink: &mut dyn clause::term::Sink,
+        ) -> core::result::Result<(), String> {
+            match self.0 {
+                ScriptedFn::Read(cursor) => ReadMock::read
+                    .each_call(matching!(_))
+                    .mutates(move |buf, _| lock(&cursor).read(buf))
+                    .deconstruct(sink),
+                ScriptedFn::BufRead(cursor) => {
+                    let consume_cursor = cursor.clone();
+                    BufReadMock::fill_buf
+                        .each_call(matching!())
+                        .answers(move |_| lock(&cursor).fill_buf().map(<[u8]>::to_vec))
+                        .deconstruct(sink)?;
+                    BufReadMock::consume
+                        .each_call(matching!(_))
+                        .answers(move |amt| lock(&consume_cursor).consume(amt))
+                        .deconstruct(sink)
+                }
+                ScriptedFn::Seek(cursor) => SeekMock::seek
+                    .each_call(matching!(_))
+                    .answers(move |pos| lock(&cursor).seek(pos))
+                    .deconstruct(sink),
+                ScriptedFn::Write(cursor) => WriteMock::write
+                    .each_call(matching!(_))
+                    .answers(move |buf| lock(&cursor).write(buf))
+                    .deconstruct(sink),
+            }
+        }
+    }
+}
+
+/// Mock APIs for `std::net` traits
+#[cfg(feature = "mock-std")]
+pub mod net {
+    use std::io::Result;
+    use std::net::{SocketAddr, ToSocketAddrs};
+
+    use crate::{unimock, Unimock};
+
+    #[unimock(prefix=crate, api=ToSocketAddrsMock, mirror=std::net::ToSocketAddrs, type Iter = std::vec::IntoIter<SocketAddr>;)]
+    pub trait ToSocketAddrs {
+        type Iter;
+
+        fn to_socket_addrs(&self) -> Result<Self::Iter>;
+    }
+
+    // Note: `std::io::IsTerminal` is sealed, so it can't be implemented for `Unimock`.
+
+    /// Host name resolution, a mockable alternative to [ToSocketAddrs] for host and port pairs.
+    ///
+    /// [StdResolver] is the real implementation. The mocked `Unimock` implementation falls back to it in partial mocks.
+    #[unimock(prefix=crate, api=ResolverMock, unmock_with=[resolve])]
+    pub trait Resolver {
+        /// Resolve the socket addresses of `host` on `port`.
+        fn resolve(&self, host: &str, port: u16) -> Result<Vec<SocketAddr>>;
+    }
+
+    /// The real [Resolver], using [ToSocketAddrs].
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct StdResolver;
+
+    impl Resolver for StdResolver {
+        fn resolve(&self, host: &str, port: u16) -> Result<Vec<SocketAddr>> {
+            Ok((host, port).to_socket_addrs()?.collect())
+        }
+    }
+
+    fn resolve(_: &Unimock, host: &str, port: u16) -> Result<Vec<SocketAddr>> {
+        StdResolver.resolve(host, port)
     }
 }
 
 /// Mock APIs for `std::process` traits
+///
+/// [Unimock](crate::Unimock) implements [Termination](std::process::Termination), so a test may return it.
+/// Reporting verifies the instance, prints any errors to stderr and returns [ExitCode::FAILURE](std::process::ExitCode::FAILURE) if verification failed.
+/// [TerminationMock::report](process::TerminationMock::report) is partial by default: Unless explicitly mocked, it reports the instance's real verification status.
+///
+/// To test `main`-like functions, [Report](process::Report) compares the [ExitCode](std::process::ExitCode) of any [Termination](std::process::Termination) value,
+/// and [Unimock::report_to](crate::Unimock::report_to) collects the verification errors into a buffer instead of stderr:
+///
+/// ```rust
+/// use std::process::{ExitCode, Termination};
+/// use unimock::*;
+/// use unimock::mock::std::process::Report;
+///
+/// #[unimock(api=ConfigMock)]
+/// trait Config {
+///     fn verbose(&self) -> bool;
+/// }
+///
+/// fn run(config: &impl Config) -> impl Termination {
+///     if config.verbose() {
+///         ExitCode::SUCCESS
+///     } else {
+///         ExitCode::from(2)
+///     }
+/// }
+///
+/// let config = Unimock::new(ConfigMock::verbose.next_call(matching!()).returns(false));
+/// assert_eq!(Report::new(run(&config)), ExitCode::from(2));
+///
+/// let mut errors = String::new();
+/// assert!(Report::new(config.report_to(&mut errors)).is_success());
+/// assert!(errors.is_empty());
+/// ```
 #[cfg(feature = "mock-std")]
 pub mod process {
-    /// Unimock mock API for [std::process::Termination].
-    #[allow(non_snake_case)]
-    pub mod TerminationMock {
-        use crate::{output::Owned, MockFn};
+    use core::fmt::Debug;
+    use std::process::ExitCode;
 
-        #[allow(non_camel_case_types)]
-        /// MockFn for [`Termination::report() -> ExitCode`](std::process::Termination::report).
-        ///
-        /// Note: This mock is partial by default.
-        /// i.e. unless explicitly mocked, it reports Unimock's real errors and status for use in tests.
-        pub struct report;
-
-        impl MockFn for report {
-            type Inputs<'i> = ();
-            type Mutation<'m> = ();
-            type Response = Owned<std::process::ExitCode>;
-            type Output<'u> = Self::Response;
-
-            fn info() -> crate::MockFnInfo {
-                let mut info = crate::MockFnInfo::new::<Self>().path("Termination", "report");
-                info.partial_by_default = true;
-                info
-            }
+    use crate::private::lib::{format, String};
+    use crate::{unimock, Unimock};
 
-            fn debug_inputs(_: &Self::Inputs<'_>) -> Vec<Option<String>> {
-                vec![]
-            }
+    #[unimock(prefix=crate, api=TerminationMock, mirror=std::process::Termination, unmock_with=[report_unmocked], partial_by_default=true)]
+    pub trait Termination {
+        fn report(self) -> ExitCode;
+    }
+
+    fn report_unmocked(mut unimock: Unimock) -> ExitCode {
+        crate::teardown::teardown_report(&mut unimock)
+    }
+
+    /// The [ExitCode] reported by a [Termination](std::process::Termination) value.
+    ///
+    /// Unlike [ExitCode], it can be compared for equality, e.g. in `assert_eq!`.
+    #[derive(Clone, Copy)]
+    pub struct Report(ExitCode);
+
+    impl Report {
+        /// Report the termination value, and capture the resulting [ExitCode].
+        pub fn new(termination: impl std::process::Termination) -> Self {
+            Self(termination.report())
+        }
+
+        /// The reported exit code.
+        pub fn exit_code(self) -> ExitCode {
+            self.0
+        }
+
+        /// Whether the exit code is [ExitCode::SUCCESS].
+        pub fn is_success(&self) -> bool {
+            *self == ExitCode::SUCCESS
+        }
+
+        /// Whether the exit code is anything else than [ExitCode::SUCCESS].
+        pub fn is_failure(&self) -> bool {
+            !self.is_success()
+        }
+
+        // ExitCode only implements PartialEq since Rust 1.79, after the MSRV of 1.65,
+        // so the comparison goes through its Debug output, which identifies the platform exit status.
+        fn debug_string(exit_code: &ExitCode) -> String {
+            format!("{exit_code:?}")
+        }
+    }
+
+    impl Debug for Report {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_tuple("Report").field(&self.0).finish()
+        }
+    }
+
+    impl PartialEq for Report {
+        fn eq(&self, other: &Self) -> bool {
+            *self == other.0
+        }
+    }
+
+    impl Eq for Report {}
+
+    impl PartialEq<ExitCode> for Report {
+        fn eq(&self, other: &ExitCode) -> bool {
+            Self::debug_string(&self.0) == Self::debug_string(other)
+        }
+    }
+
+    impl From<Report> for ExitCode {
+        fn from(report: Report) -> Self {
+            report.0
         }
     }
+
+    impl std::process::Termination for Report {
+        fn report(self) -> ExitCode {
+            self.0
+        }
+    }
+}
+
+/// Mock APIs for `std::time` abstractions
+///
+/// [Instant::now](std::time::Instant::now) and [SystemTime::now](std::time::SystemTime::now) can't be mocked.
+/// [Clock](time::Clock) is a trait for reading (and waiting for) the time, implemented for [StdClock](time::StdClock) in release code.
+/// The mocked `Unimock` implementation falls back to the system clock in partial mocks:
+///
+/// ```rust
+/// use unimock::*;
+/// use unimock::mock::std::time::{Clock, ClockMock};
+/// use std::time::{Duration, SystemTime};
+///
+/// let clock = Unimock::new_partial(
+///     ClockMock::system_time
+///         .next_call(matching!())
+///         .returns(SystemTime::UNIX_EPOCH),
+/// );
+///
+/// assert_eq!(SystemTime::UNIX_EPOCH, clock.system_time());
+/// // Not mocked, so this is the real time:
+/// assert!(clock.now().elapsed() < Duration::from_secs(1));
+/// ```
+#[cfg(feature = "mock-std")]
+pub mod time {
+    use std::time::{Duration, Instant, SystemTime};
+
+    use crate::{unimock, Unimock};
+
+    /// A source of time.
+    #[unimock(prefix=crate, api=ClockMock, unmock_with=[now, system_time, sleep])]
+    pub trait Clock {
+        /// The current monotonic time, see [Instant::now].
+        fn now(&self) -> Instant;
+        /// The current system time, see [SystemTime::now].
+        fn system_time(&self) -> SystemTime;
+        /// Block the current thread for the given duration, see [std::thread::sleep].
+        fn sleep(&self, duration: Duration);
+    }
+
+    /// The real [Clock], using the system clock.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct StdClock;
+
+    impl Clock for StdClock {
+        fn now(&self) -> Instant {
+            Instant::now()
+        }
+
+        fn system_time(&self) -> SystemTime {
+            SystemTime::now()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            std::thread::sleep(duration)
+        }
+    }
+
+    fn now(_: &Unimock) -> Instant {
+        StdClock.now()
+    }
+
+    fn system_time(_: &Unimock) -> SystemTime {
+        StdClock.system_time()
+    }
+
+    fn sleep(_: &Unimock, duration: Duration) {
+        StdClock.sleep(duration)
+    }
 }
@@ -1,7 +1,8 @@
-use core::borrow::Borrow;
+use core::borrow::{Borrow, BorrowMut};
 
-use crate::private::lib::Box;
-use crate::{call_pattern::DynResponder, value_chain::ValueChain, MockFn, Responder};
+use crate::private::lib::{Arc, Box};
+use crate::private::DynMock;
+use crate::{call_pattern::DynResponder, value_chain::ValueChain, MockFn, Responder, Unimock};
 
 #[derive(Debug)]
 #[doc(hidden)]
@@ -39,6 +40,28 @@ pub trait IntoCloneResponder<R: Respond>: IntoOnceResponder<R> {
     fn into_clone_responder<F: MockFn<Response = R>>(self) -> OutputResult<Responder>;
 }
 
+/// Smart pointers to trait objects that can be created from a child [Unimock] instance.
+///
+/// This is implemented for `Box<dyn Trait>` and `Arc<dyn Trait>` (optionally with `+ Send` or `+ Send + Sync`),
+/// when `Trait` is a dyn-compatible trait mocked with `#[unimock(dyn_mock=true)]`.
+/// See [returns_mock](crate::build::DefineResponse::returns_mock).
+pub trait MockPointer: Sized {
+    #[doc(hidden)]
+    fn from_unimock(unimock: Unimock) -> Self;
+}
+
+impl<T: ?Sized + DynMock<Unimock>> MockPointer for Box<T> {
+    fn from_unimock(unimock: Unimock) -> Self {
+        T::boxed(unimock)
+    }
+}
+
+impl<T: ?Sized + DynMock<Unimock>> MockPointer for Arc<T> {
+    fn from_unimock(unimock: Unimock) -> Self {
+        Arc::from(T::boxed(unimock))
+    }
+}
+
 /// Trait that describes the output of a mocked function, and how responses are converted into that type.
 ///
 /// The trait uses the 'u lifetime, which is the lifetime of unimock itself.
@@ -54,24 +77,39 @@ pub trait Output<'u, R: Respond> {
     fn try_from_borrowed_response(response: &'u R::Type) -> OutputResult<Self::Type>;
 }
 
-#[doc(hidden)]
+/// Trait that describes the output of a mocked `&mut self` method that borrows mutably from the instance,
+/// e.g. `&mut T` or `Option<&mut T>`.
+///
+/// The [Output] of such a method is the response itself, which is converted with exclusive access to the instance.
+pub trait OutputMut<'u>: Respond {
+    /// The type of the output compatible with the function signature.
+    type Type;
+
+    #[doc(hidden)]
+    fn from_response_mut(
+        response: <Self as Respond>::Type,
+        value_chain: &'u mut ValueChain,
+    ) -> <Self as OutputMut<'u>>::Type;
+}
+
+/// Describes a function response that is an owned value.
 pub struct Owned<T>(core::marker::PhantomData<T>);
 
-// This type describes a function response that is a reference borrowed from `Self`.
-#[doc(hidden)]
+/// Describes a function response that is a reference borrowed from `Self`.
 pub struct Borrowed<T: ?Sized + 'static>(core::marker::PhantomData<T>);
 
+/// Describes a function response that is a mutable reference borrowed from `Self`.
+pub struct BorrowedMut<T: ?Sized + 'static>(core::marker::PhantomData<T>);
+
 #[doc(hidden)]
 pub struct StaticRef<T: ?Sized>(core::marker::PhantomData<T>);
 
-// This type describes a function response that is a mix of owned and borrowed data.
-//
-// The typical example is `Option<&T>`.
-#[doc(hidden)]
+/// Describes a function response that is a mix of owned and borrowed data.
+///
+/// The typical example is `Option<&T>`, which is described as `Mixed<Option<Borrowed<T>>>`.
+/// The type parameter is a [MixedContainer] parameterized by other response descriptors.
 pub struct Mixed<T>(core::marker::PhantomData<T>);
 
-type BoxBorrow<T> = Box<dyn Borrow<T> + Send + Sync>;
-
 mod owned {
     use super::*;
 
@@ -119,13 +157,21 @@ mod owned {
             Err(ResponderError::OwnershipRequired)
         }
     }
+
+    impl<'u, T: 'static> OutputMut<'u> for Owned<T> {
+        type Type = T;
+
+        fn from_response_mut(response: T, _: &'u mut ValueChain) -> T {
+            response
+        }
+    }
 }
 
 mod borrowed {
     use super::*;
 
     impl<T: ?Sized + 'static> Respond for Borrowed<T> {
-        type Type = Box<dyn Borrow<T> + Send + Sync>;
+        type Type = Arc<dyn Borrow<T> + Send + Sync>;
     }
 
     impl<T0, T> IntoResponse<Borrowed<T>> for T0
@@ -134,7 +180,7 @@ mod borrowed {
         T: ?Sized + 'static,
     {
         fn into_response(self) -> <Borrowed<T> as Respond>::Type {
-            Box::new(self)
+            Arc::new(self)
         }
     }
 
@@ -179,6 +225,87 @@ mod borrowed {
             Ok(response.as_ref().borrow())
         }
     }
+
+    impl<'u, T: ?Sized + 'static> OutputMut<'u> for Borrowed<T> {
+        type Type = &'u T;
+
+        fn from_response_mut(
+            response: <Self as Respond>::Type,
+            value_chain: &'u mut ValueChain,
+        ) -> &'u T {
+            <Self as Output<'u, Self>>::from_response(response, value_chain)
+        }
+    }
+}
+
+mod borrowed_mut {
+    use super::*;
+
+    impl<T: ?Sized + 'static> Respond for BorrowedMut<T> {
+        type Type = Box<dyn BorrowMut<T> + Send + Sync>;
+    }
+
+    impl<T0, T> IntoResponse<BorrowedMut<T>> for T0
+    where
+        T0: BorrowMut<T> + Send + Sync + 'static,
+        T: ?Sized + 'static,
+    {
+        fn into_response(self) -> <BorrowedMut<T> as Respond>::Type {
+            Box::new(self)
+        }
+    }
+
+    impl<T0, T> IntoOnceResponder<BorrowedMut<T>> for T0
+    where
+        T0: BorrowMut<T> + Send + Sync + 'static,
+        T: ?Sized + 'static,
+    {
+        fn into_once_responder<F: MockFn<Response = BorrowedMut<T>>>(
+            self,
+        ) -> OutputResult<Responder> {
+            let response = <T0 as IntoResponse<BorrowedMut<T>>>::into_response(self);
+            Ok(Responder(DynResponder::new_cell::<F>(response)?))
+        }
+    }
+
+    // Every call mutably borrows its own clone of the value.
+    impl<T0, T> IntoCloneResponder<BorrowedMut<T>> for T0
+    where
+        T0: BorrowMut<T> + Clone + Send + Sync + 'static,
+        T: ?Sized + 'static,
+    {
+        fn into_clone_responder<F: MockFn<Response = BorrowedMut<T>>>(
+            self,
+        ) -> OutputResult<Responder> {
+            Ok(Responder(DynResponder::new_clone_factory_cell::<F>(
+                move || Some(Box::new(self.clone())),
+            )))
+        }
+    }
+
+    // The output is the response itself, which is later stored with `OutputMut`.
+    impl<'u, T: ?Sized + 'static> Output<'u, Self> for BorrowedMut<T> {
+        type Type = <Self as Respond>::Type;
+
+        fn from_response(response: <Self as Respond>::Type, _: &'u ValueChain) -> Self::Type {
+            response
+        }
+
+        fn try_from_borrowed_response(_: &'u <Self as Respond>::Type) -> OutputResult<Self::Type> {
+            Err(ResponderError::OwnershipRequired)
+        }
+    }
+
+    impl<'u, T: ?Sized + 'static> OutputMut<'u> for BorrowedMut<T> {
+        type Type = &'u mut T;
+
+        fn from_response_mut(
+            response: <Self as Respond>::Type,
+            value_chain: &'u mut ValueChain,
+        ) -> &'u mut T {
+            value_chain.add_mut(response).as_mut().borrow_mut()
+        }
+    }
 }
 
 mod static_ref {
@@ -211,6 +338,14 @@ mod static_ref {
         }
     }
 
+    impl<'u, T: ?Sized + 'static> OutputMut<'u> for StaticRef<T> {
+        type Type = &'static T;
+
+        fn from_response_mut(value: &'static T, _: &'u mut ValueChain) -> &'static T {
+            value
+        }
+    }
+
     impl<'u, T: ?Sized + 'static> Output<'u, Self> for StaticRef<T> {
         type Type = &'static T;
 
@@ -226,389 +361,498 @@ mod static_ref {
     }
 }
 
-// TODO: Generalize in mixed enum macro.
-mod mixed_option {
-    use super::*;
+/// A generic type that may appear in a [Mixed] output, e.g. `Option<T>`, `Vec<T>` or `HashMap<K, V>`.
+///
+/// The container's type parameters are response descriptors ([Owned], [Borrowed] or [Mixed]),
+/// so `Result<&T, E>` is described by the container `Result<Borrowed<T>, Owned<E>>`.
+///
+/// Unimock implements this for tuples and for common container types.
+/// It can be implemented for a user-defined generic type in order to return it
+/// with references borrowed from `Self` from a mocked method,
+/// along with [MixedFrom] for the corresponding value type:
+///
+/// ```rust
+/// use unimock::*;
+/// use unimock::output::*;
+/// use unimock::value_chain::ValueChain;
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// pub enum Lookup<T> {
+///     Found(T),
+///     Missing,
+/// }
+///
+/// impl<D: MixedPart> MixedContainer for Lookup<D> {
+///     type Response = Lookup<<D as Respond>::Type>;
+///     type Output<'u> = Lookup<<D as Output<'u, D>>::Type>;
+///
+///     fn from_response<'u>(response: Self::Response, value_chain: &'u ValueChain) -> Self::Output<'u> {
+///         match response {
+///             Lookup::Found(value) => Lookup::Found(D::from_response(value, value_chain)),
+///             Lookup::Missing => Lookup::Missing,
+///         }
+///     }
+///
+///     fn try_from_borrowed_response<'u>(
+///         response: &'u Self::Response,
+///     ) -> Result<Self::Output<'u>, ResponderError> {
+///         Ok(match response {
+///             Lookup::Found(value) => Lookup::Found(D::try_from_borrowed_response(value)?),
+///             Lookup::Missing => Lookup::Missing,
+///         })
+///     }
+/// }
+///
+/// impl<A, D: FromMixedValue<A>> MixedFrom<Lookup<A>> for Lookup<D> {
+///     fn from_value(value: Lookup<A>) -> Lookup<<D as Respond>::Type> {
+///         match value {
+///             Lookup::Found(value) => Lookup::Found(D::from_mixed_value(value)),
+///             Lookup::Missing => Lookup::Missing,
+///         }
+///     }
+/// }
+///
+/// #[unimock(api = DirectoryMock)]
+/// trait Directory {
+///     fn lookup(&self, name: &str) -> Lookup<&str>;
+/// }
+///
+/// let u = Unimock::new(
+///     DirectoryMock::lookup
+///         .each_call(matching!("alice"))
+///         .returns(Lookup::Found("admin".to_string())),
+/// );
+/// assert_eq!(Lookup::Found("admin"), u.lookup("alice"));
+/// ```
+pub trait MixedContainer: 'static {
+    /// The type of the response, as stored temporarily inside Unimock.
+    type Response: 'static;
 
-    type Mix<T> = Mixed<Option<&'static T>>;
+    /// The type of the output, which may borrow from the stored response.
+    type Output<'u>;
 
-    impl<T: ?Sized + 'static> Respond for Mix<T> {
-        type Type = Option<BoxBorrow<T>>;
-    }
+    /// Convert an owned response into the output, storing borrowed parts in the value chain.
+    fn from_response<'u>(response: Self::Response, value_chain: &'u ValueChain)
+        -> Self::Output<'u>;
 
-    impl<T0, T> IntoResponse<Mix<T>> for Option<T0>
-    where
-        T0: Borrow<T> + Send + Sync + 'static,
-        T: ?Sized + 'static,
-    {
-        fn into_response(self) -> <Mix<T> as Respond>::Type {
-            match self {
-                Some(value) => Some(Box::new(value)),
-                None => None,
-            }
-        }
-    }
+    /// Try to convert a borrowed response into the output.
+    ///
+    /// This should fail when the response contains an [Owned] part.
+    fn try_from_borrowed_response<'u>(
+        response: &'u Self::Response,
+    ) -> OutputResult<Self::Output<'u>>;
+}
 
-    impl<T0, T> IntoOnceResponder<Mix<T>> for Option<T0>
-    where
-        T0: Borrow<T> + Send + Sync + 'static,
-        T: ?Sized + 'static,
-    {
-        fn into_once_responder<F: MockFn<Response = Mix<T>>>(self) -> OutputResult<Responder> {
-            let response = <Self as IntoResponse<Mix<T>>>::into_response(self);
-            Ok(Responder(DynResponder::new_borrow::<F>(response)))
-        }
-    }
+/// A response descriptor that can be a part of a [MixedContainer].
+///
+/// This is implemented for [Owned], [Borrowed] and [Mixed].
+pub trait MixedPart: Respond + for<'u> Output<'u, Self> + Sized + 'static {}
 
-    impl<T0, T> IntoCloneResponder<Mix<T>> for Option<T0>
-    where
-        T0: Borrow<T> + Send + Sync + 'static,
-        T: ?Sized + 'static,
-    {
-        fn into_clone_responder<F: MockFn<Response = Mix<T>>>(self) -> OutputResult<Responder> {
-            let response = <Self as IntoResponse<Mix<T>>>::into_response(self);
-            Ok(Responder(DynResponder::new_borrow::<F>(response)))
-        }
+impl<R: Respond + for<'u> Output<'u, R> + 'static> MixedPart for R {}
+
+/// A [MixedPart] that can be created from a value of type `A`.
+///
+/// Unlike [IntoResponse], an [Owned] part must be given as exactly the owned type,
+/// which keeps type inference working for values like `Ok(42)`.
+pub trait FromMixedValue<A>: MixedPart {
+    /// Convert the value into the response part.
+    fn from_mixed_value(value: A) -> <Self as Respond>::Type;
+}
+
+/// A [MixedContainer] whose response can be created from a value of type `A`.
+///
+/// E.g. `Option<Borrowed<str>>` can be created from `Option<String>` or `Option<&'static str>`.
+pub trait MixedFrom<A>: MixedContainer {
+    /// Convert the value into the response.
+    fn from_value(value: A) -> Self::Response;
+}
+
+mod mixed {
+    use core::task::Poll;
+
+    use crate::private::lib::{BTreeMap, Cow, ToOwned, Vec, VecDeque};
+
+    use super::*;
+
+    impl<X: MixedContainer> Respond for Mixed<X> {
+        type Type = X::Response;
     }
 
-    impl<'u, T> Output<'u, Mix<T>> for Mixed<Option<&'u T>>
-    where
-        T: ?Sized + 'u,
-    {
-        type Type = Option<&'u T>;
+    impl<'u, X: MixedContainer> Output<'u, Self> for Mixed<X> {
+        type Type = X::Output<'u>;
 
-        fn from_response(
-            response: <Mix<T> as Respond>::Type,
-            value_chain: &'u ValueChain,
-        ) -> Self::Type {
-            response.map(|value| value_chain.add(value).as_ref().borrow())
+        fn from_response(response: X::Response, value_chain: &'u ValueChain) -> Self::Type {
+            X::from_response(response, value_chain)
         }
 
-        fn try_from_borrowed_response(
-            response: &'u <Mix<T> as Respond>::Type,
-        ) -> OutputResult<Self::Type> {
-            Ok(response.as_ref().map(|value| value.as_ref().borrow()))
+        fn try_from_borrowed_response(response: &'u X::Response) -> OutputResult<Self::Type> {
+            X::try_from_borrowed_response(response)
         }
     }
-}
-
-mod mixed_vec {
-    use crate::private::lib::Vec;
-
-    use super::*;
 
-    type Mix<T> = Mixed<Vec<&'static T>>;
+    impl<A, X: MixedFrom<A>> FromMixedValue<A> for Mixed<X> {
+        fn from_mixed_value(value: A) -> X::Response {
+            X::from_value(value)
+        }
+    }
 
-    impl<T: ?Sized + 'static> Respond for Mix<T> {
-        type Type = Vec<BoxBorrow<T>>;
+    impl<T: 'static> FromMixedValue<T> for Owned<T> {
+        fn from_mixed_value(value: T) -> T {
+            value
+        }
     }
 
-    impl<T0, T> IntoResponse<Mix<T>> for Vec<T0>
+    impl<T0, T> FromMixedValue<T0> for Borrowed<T>
     where
         T0: Borrow<T> + Send + Sync + 'static,
         T: ?Sized + 'static,
     {
-        fn into_response(self) -> <Mix<T> as Respond>::Type {
-            self.into_iter()
-                .map(|item| -> BoxBorrow<T> { Box::new(item) })
-                .collect()
+        fn from_mixed_value(value: T0) -> <Self as Respond>::Type {
+            Arc::new(value)
         }
     }
 
-    impl<T0, T> IntoOnceResponder<Mix<T>> for Vec<T0>
+    impl<T0, T> FromMixedValue<T0> for BorrowedMut<T>
     where
-        T0: Borrow<T> + Send + Sync + 'static,
+        T0: BorrowMut<T> + Send + Sync + 'static,
         T: ?Sized + 'static,
     {
-        fn into_once_responder<F: MockFn<Response = Mix<T>>>(self) -> OutputResult<Responder> {
-            let response = <Self as IntoResponse<Mix<T>>>::into_response(self);
-            Ok(Responder(DynResponder::new_borrow::<F>(response)))
+        fn from_mixed_value(value: T0) -> <Self as Respond>::Type {
+            Box::new(value)
         }
     }
 
-    impl<T0, T> IntoCloneResponder<Mix<T>> for Vec<T0>
+    impl<A, X> IntoResponse<Mixed<X>> for A
     where
-        T0: Borrow<T> + Send + Sync + 'static,
-        T: ?Sized + 'static,
+        X: MixedFrom<A>,
     {
-        fn into_clone_responder<F: MockFn<Response = Mix<T>>>(self) -> OutputResult<Responder> {
-            let response = <Self as IntoResponse<Mix<T>>>::into_response(self);
-            Ok(Responder(DynResponder::new_borrow::<F>(response)))
+        fn into_response(self) -> X::Response {
+            X::from_value(self)
         }
     }
 
-    impl<'u, T> Output<'u, Mix<T>> for Mixed<Vec<&'u T>>
+    // When every part of the response can be borrowed, the responder may respond any number of times
+    // without cloning, the same way as a plain [Borrowed] response.
+    impl<A, X> IntoOnceResponder<Mixed<X>> for A
     where
-        T: ?Sized + 'u,
+        X: MixedFrom<A>,
+        X::Response: Send + Sync,
     {
-        type Type = Vec<&'u T>;
-
-        fn from_response(_: <Mix<T> as Respond>::Type, _: &'u ValueChain) -> Self::Type {
-            panic!()
+        fn into_once_responder<F: MockFn<Response = Mixed<X>>>(self) -> OutputResult<Responder> {
+            let response = X::from_value(self);
+            if X::try_from_borrowed_response(&response).is_ok() {
+                Ok(Responder(DynResponder::new_borrow::<F>(response)))
+            } else {
+                Ok(Responder(DynResponder::new_cell::<F>(response)?))
+            }
         }
+    }
 
-        fn try_from_borrowed_response(
-            response: &'u <Mix<T> as Respond>::Type,
-        ) -> OutputResult<Self::Type> {
-            Ok(response.iter().map(|b| b.as_ref().borrow()).collect())
+    // Borrowed parts are reference counted, so `X::Response: Clone` only requires the owned parts to be `Clone`.
+    impl<A, X> IntoCloneResponder<Mixed<X>> for A
+    where
+        X: MixedFrom<A>,
+        X::Response: Clone + Send + Sync,
+    {
+        fn into_clone_responder<F: MockFn<Response = Mixed<X>>>(self) -> OutputResult<Responder> {
+            let response = X::from_value(self);
+            if X::try_from_borrowed_response(&response).is_ok() {
+                Ok(Responder(DynResponder::new_borrow::<F>(response)))
+            } else {
+                Ok(Responder(DynResponder::new_clone_cell::<F>(response)))
+            }
         }
     }
-}
 
-// TODO: Generalize in mixed enum macro.
-mod mixed_result_borrowed_t {
-    use super::*;
+    impl<D: MixedPart> MixedContainer for Option<D> {
+        type Response = Option<<D as Respond>::Type>;
+        type Output<'u> = Option<<D as Output<'u, D>>::Type>;
 
-    type Mix<T, E> = Mixed<Result<&'static T, E>>;
+        fn from_response<'u>(
+            response: Self::Response,
+            value_chain: &'u ValueChain,
+        ) -> Self::Output<'u> {
+            response.map(|value| D::from_response(value, value_chain))
+        }
 
-    impl<T: ?Sized + 'static, E: 'static> Respond for Mix<T, E> {
-        type Type = Result<BoxBorrow<T>, E>;
+        fn try_from_borrowed_response<'u>(
+            response: &'u Self::Response,
+        ) -> OutputResult<Self::Output<'u>> {
+            response
+                .as_ref()
+                .map(D::try_from_borrowed_response)
+                .transpose()
+        }
     }
 
-    impl<T0, T, E> IntoResponse<Mix<T, E>> for Result<T0, E>
-    where
-        T0: Borrow<T> + Send + Sync + 'static,
-        T: ?Sized + 'static,
-        E: Send + Sync + 'static,
-    {
-        fn into_response(self) -> <Mix<T, E> as Respond>::Type {
-            match self {
-                Ok(value) => Ok(Box::new(value)),
-                Err(e) => Err(e),
-            }
+    impl<A, D: FromMixedValue<A>> MixedFrom<Option<A>> for Option<D> {
+        fn from_value(value: Option<A>) -> Option<<D as Respond>::Type> {
+            value.map(D::from_mixed_value)
         }
     }
 
-    impl<T0, T, E> IntoOnceResponder<Mix<T, E>> for Result<T0, E>
-    where
-        T0: Borrow<T> + Send + Sync + 'static,
-        T: ?Sized + 'static,
-        E: Send + Sync + 'static,
-    {
-        fn into_once_responder<F: MockFn<Response = Mix<T, E>>>(self) -> OutputResult<Responder> {
-            match self {
-                // In the Ok variant we make a multi-value responder out of it anyway:
-                Ok(value) => Ok(Responder(DynResponder::new_borrow::<F>(Ok(Box::new(
-                    value,
-                ))))),
-                // The Err variant can only be used once:
-                Err(error) => Ok(Responder(DynResponder::new_cell::<F>(Err(error))?)),
+    impl<D: MixedPart, E: MixedPart> MixedContainer for Result<D, E> {
+        type Response = Result<<D as Respond>::Type, <E as Respond>::Type>;
+        type Output<'u> = Result<<D as Output<'u, D>>::Type, <E as Output<'u, E>>::Type>;
+
+        fn from_response<'u>(
+            response: Self::Response,
+            value_chain: &'u ValueChain,
+        ) -> Self::Output<'u> {
+            match response {
+                Ok(value) => Ok(D::from_response(value, value_chain)),
+                Err(error) => Err(E::from_response(error, value_chain)),
             }
         }
+
+        fn try_from_borrowed_response<'u>(
+            response: &'u Self::Response,
+        ) -> OutputResult<Self::Output<'u>> {
+            Ok(match response {
+                Ok(value) => Ok(D::try_from_borrowed_response(value)?),
+                Err(error) => Err(E::try_from_borrowed_response(error)?),
+            })
+        }
     }
 
-    impl<T0, T, E> IntoCloneResponder<Mix<T, E>> for Result<T0, E>
+    impl<A, B, D, E> MixedFrom<Result<A, B>> for Result<D, E>
     where
-        T0: Borrow<T> + Send + Sync + 'static,
-        T: ?Sized + 'static,
-        E: Clone + Send + Sync + 'static,
+        D: FromMixedValue<A>,
+        E: FromMixedValue<B>,
     {
-        fn into_clone_responder<F: MockFn<Response = Mix<T, E>>>(self) -> OutputResult<Responder> {
-            match self {
-                // There is no `T0: Clone` bound, because it just uses the borrow responder mechanism:
-                Ok(value) => Ok(Responder(DynResponder::new_borrow::<F>(Ok(Box::new(
-                    value,
-                ))))),
-                // We have `E: Clone` because the E is in fact owned...
-                Err(error) => Ok(Responder(DynResponder::new_clone_factory_cell::<F>(
-                    move || Some(Err(error.clone())),
-                ))),
+        fn from_value(value: Result<A, B>) -> Result<<D as Respond>::Type, <E as Respond>::Type> {
+            match value {
+                Ok(value) => Ok(D::from_mixed_value(value)),
+                Err(error) => Err(E::from_mixed_value(error)),
             }
         }
     }
 
-    impl<'u, T, E: 'static> Output<'u, Mix<T, E>> for Mixed<Result<&'u T, E>>
-    where
-        T: ?Sized + 'u,
-    {
-        type Type = Result<&'u T, E>;
+    impl<D: MixedPart> MixedContainer for Poll<D> {
+        type Response = Poll<<D as Respond>::Type>;
+        type Output<'u> = Poll<<D as Output<'u, D>>::Type>;
 
-        fn from_response(
-            response: <Mix<T, E> as Respond>::Type,
+        fn from_response<'u>(
+            response: Self::Response,
             value_chain: &'u ValueChain,
-        ) -> Self::Type {
-            match response {
-                Ok(value) => Ok(value_chain.add(value).as_ref().borrow()),
-                Err(e) => Err(e),
-            }
+        ) -> Self::Output<'u> {
+            response.map(|value| D::from_response(value, value_chain))
         }
 
-        fn try_from_borrowed_response(
-            response: &'u <Mix<T, E> as Respond>::Type,
-        ) -> OutputResult<Self::Type> {
-            match response {
-                Ok(value) => Ok(Ok(value.as_ref().borrow())),
-                // No chance of converting the E into owned here:
-                Err(_) => Err(ResponderError::OwnershipRequired),
-            }
+        fn try_from_borrowed_response<'u>(
+            response: &'u Self::Response,
+        ) -> OutputResult<Self::Output<'u>> {
+            Ok(match response {
+                Poll::Ready(value) => Poll::Ready(D::try_from_borrowed_response(value)?),
+                Poll::Pending => Poll::Pending,
+            })
         }
     }
-}
 
-macro_rules! mixed_tuples {
-    ($(($t:ident, $a:ident, $i:tt)),+) => {
-        impl<$($t: Respond),+> Respond for Mixed<($($t),+,)> {
-            type Type = ($(<$t as Respond>::Type),+,);
-        }
-
-        impl<$($t),+, $($a),+> IntoResponse<Mixed<($($t),+,)>> for ($($a),+,)
-        where
-            $($t: Respond),+,
-            $(<$t as Respond>::Type: Send + Sync),+,
-            $($a: IntoResponse<$t>),+,
-        {
-            fn into_response(self) -> <Mixed<($($t),+,)> as Respond>::Type {
-                ($(self.$i.into_response()),+,)
-            }
+    impl<A, D: FromMixedValue<A>> MixedFrom<Poll<A>> for Poll<D> {
+        fn from_value(value: Poll<A>) -> Poll<<D as Respond>::Type> {
+            value.map(D::from_mixed_value)
         }
+    }
 
-        impl<$($t),+, $($a),+> IntoOnceResponder<Mixed<($($t),+,)>> for ($($a),+,)
-        where
-            $($t: Respond),+,
-            $(<$t as Respond>::Type: Send + Sync),+,
-            $($a: IntoResponse<$t>),+,
-        {
-            fn into_once_responder<F: MockFn<Response = Mixed<($($t),+,)>>>(self) -> OutputResult<Responder> {
-                let response = <Self as IntoResponse<Mixed<($($t),+,)>>>::into_response(self);
-                Ok(Responder(DynResponder::new_cell::<F>(response)?))
-            }
-        }
+    /// Implements [MixedContainer] and [MixedFrom] for a sequence collection.
+    macro_rules! mixed_sequence {
+        ($ty:ident) => {
+            impl<D: MixedPart> MixedContainer for $ty<D> {
+                type Response = $ty<<D as Respond>::Type>;
+                type Output<'u> = $ty<<D as Output<'u, D>>::Type>;
 
+                fn from_response<'u>(
+                    response: Self::Response,
+                    value_chain: &'u ValueChain,
+                ) -> Self::Output<'u> {
+                    response
+                        .into_iter()
+                        .map(|item| D::from_response(item, value_chain))
+                        .collect()
+                }
 
-        impl<$($t),+, $($a),+> IntoCloneResponder<Mixed<($($t),+,)>> for ($($a),+,)
-        where
-            $($t: Respond),+,
-            $(<$t as Respond>::Type: Clone + Send + Sync),+,
-            $($a: IntoCloneResponder<$t>),+,
-        {
-            fn into_clone_responder<F: MockFn<Response = Mixed<($($t),+,)>>>(self) -> OutputResult<Responder> {
-                let response = <Self as IntoResponse<Mixed<($($t),+,)>>>::into_response(self);
-                Ok(Responder(DynResponder::new_clone_cell::<F>(response)))
+                fn try_from_borrowed_response<'u>(
+                    response: &'u Self::Response,
+                ) -> OutputResult<Self::Output<'u>> {
+                    response.iter().map(D::try_from_borrowed_response).collect()
+                }
             }
-        }
 
-        impl<'u, $($t),+, $($a),+> Output<'u, Mixed<($($t),+,)>> for Mixed<($($a),+,)>
-        where
-            $($t: Respond),+,
-            $($a: Output<'u, $t>),+,
-        {
-            type Type = ($(<$a as Output<'u, $t>>::Type),+,);
-
-            fn from_response(
-                response: <Mixed<($($t),+,)> as Respond>::Type,
-                value_chain: &'u ValueChain,
-            ) -> Self::Type {
-                (
-                    $(<$a as Output<'u, $t>>::from_response(response.$i, value_chain)),+,
-                )
+            impl<A, D: FromMixedValue<A>> MixedFrom<$ty<A>> for $ty<D> {
+                fn from_value(value: $ty<A>) -> $ty<<D as Respond>::Type> {
+                    value.into_iter().map(D::from_mixed_value).collect()
+                }
+            }
+        };
+    }
+
+    mixed_sequence!(Vec);
+    mixed_sequence!(VecDeque);
+
+    /// Implements [MixedContainer] and [MixedFrom] for a map collection.
+    ///
+    /// The entries are stored in a `Vec`, because a borrowed key is only comparable after it has been output.
+    macro_rules! mixed_map {
+        ($ty:ident, $($key_bound:tt)+) => {
+            impl<K: MixedPart, V: MixedPart> MixedContainer for $ty<K, V>
+            where
+                for<'u> <K as Output<'u, K>>::Type: $($key_bound)+,
+            {
+                type Response = Vec<(<K as Respond>::Type, <V as Respond>::Type)>;
+                type Output<'u> = $ty<<K as Output<'u, K>>::Type, <V as Output<'u, V>>::Type>;
+
+                fn from_response<'u>(
+                    response: Self::Response,
+                    value_chain: &'u ValueChain,
+                ) -> Self::Output<'u> {
+                    response
+                        .into_iter()
+                        .map(|(key, value)| {
+                            (
+                                K::from_response(key, value_chain),
+                                V::from_response(value, value_chain),
+                            )
+                        })
+                        .collect()
+                }
+
+                fn try_from_borrowed_response<'u>(
+                    response: &'u Self::Response,
+                ) -> OutputResult<Self::Output<'u>> {
+                    response
+                        .iter()
+                        .map(|(key, value)| {
+                            Ok((
+                                K::try_from_borrowed_response(key)?,
+                                V::try_from_borrowed_response(value)?,
+                            ))
+                        })
+                        .collect()
+                }
             }
 
-            fn try_from_borrowed_response(
-                response: &'u <Mixed<($($t),+,)> as Respond>::Type,
-            ) -> OutputResult<Self::Type> {
-                Ok((
-                    $(<$a as Output<'u, $t>>::try_from_borrowed_response(&response.$i)?),+,
-                ))
+            impl<A, B, K, V> MixedFrom<$ty<A, B>> for $ty<K, V>
+            where
+                K: FromMixedValue<A>,
+                V: FromMixedValue<B>,
+                for<'u> <K as Output<'u, K>>::Type: $($key_bound)+,
+            {
+                fn from_value(value: $ty<A, B>) -> Vec<(<K as Respond>::Type, <V as Respond>::Type)> {
+                    value
+                        .into_iter()
+                        .map(|(key, value)| (K::from_mixed_value(key), V::from_mixed_value(value)))
+                        .collect()
+                }
             }
-        }
-    };
-}
+        };
+    }
 
-mixed_tuples!((T0, A0, 0));
-mixed_tuples!((T0, A0, 0), (T1, A1, 1));
-mixed_tuples!((T0, A0, 0), (T1, A1, 1), (T2, A2, 2));
-mixed_tuples!((T0, A0, 0), (T1, A1, 1), (T2, A2, 2), (T3, A3, 3));
+    mixed_map!(BTreeMap, Ord);
 
-// This can perhaps serve as the template for a macro that handles Mixed enums
-mod mixed_poll {
-    use super::*;
-    use core::task::Poll;
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
 
-    type Mix<T> = Mixed<Poll<T>>;
+    #[cfg(feature = "std")]
+    mixed_map!(HashMap, Eq + core::hash::Hash);
 
-    impl<T> Respond for Mix<T>
-    where
-        Mixed<T>: Respond,
-        <Mixed<T> as Respond>::Type: 'static + Send + Sync,
-    {
-        type Type = Poll<<Mixed<T> as Respond>::Type>;
-    }
+    // `Cow<'_, T>` is stored as the owned value, and is output as `Cow::Borrowed` whenever possible.
+    impl<T: ?Sized + ToOwned + 'static> MixedContainer for Cow<'static, T> {
+        type Response = T::Owned;
+        type Output<'u> = Cow<'u, T>;
 
-    impl<T0, T> IntoResponse<Mix<T>> for Poll<T0>
-    where
-        T0: IntoResponse<Mixed<T>>,
-        Mixed<T>: Respond,
-        <Mixed<T> as Respond>::Type: 'static + Send + Sync,
-    {
-        fn into_response(self) -> <Mix<T> as Respond>::Type {
-            match self {
-                Poll::Ready(value) => Poll::Ready(value.into_response()),
-                Poll::Pending => Poll::Pending,
-            }
+        fn from_response<'u>(response: Self::Response, _: &'u ValueChain) -> Self::Output<'u> {
+            Cow::Owned(response)
+        }
+
+        fn try_from_borrowed_response<'u>(
+            response: &'u Self::Response,
+        ) -> OutputResult<Self::Output<'u>> {
+            Ok(Cow::Borrowed(response.borrow()))
         }
     }
 
-    impl<T0, T> IntoOnceResponder<Mix<T>> for Poll<T0>
+    impl<A, T> MixedFrom<A> for Cow<'static, T>
     where
-        T0: IntoResponse<Mixed<T>>,
-        Mixed<T>: Respond,
-        <Mixed<T> as Respond>::Type: 'static + Send + Sync,
+        A: Into<T::Owned>,
+        T: ?Sized + ToOwned + 'static,
     {
-        fn into_once_responder<F: MockFn<Response = Mix<T>>>(self) -> OutputResult<Responder> {
-            match self {
-                Poll::Ready(value) => Ok(Responder(DynResponder::new_cell::<F>(Poll::Ready(
-                    value.into_response(),
-                ))?)),
-                Poll::Pending => Ok(Responder(DynResponder::new_cell::<F>(Poll::Pending)?)),
-            }
+        fn from_value(value: A) -> T::Owned {
+            value.into()
         }
     }
 
-    impl<T0, T> IntoCloneResponder<Mix<T>> for Poll<T0>
-    where
-        T0: IntoResponse<Mixed<T>>,
-        Mixed<T>: Respond,
-        <Mixed<T> as Respond>::Type: 'static + Send + Sync + Clone,
-    {
-        fn into_clone_responder<F: MockFn<Response = Mix<T>>>(self) -> OutputResult<Responder> {
-            match self {
-                Poll::Ready(value) => Ok(Responder(DynResponder::new_clone_cell::<F>(
-                    Poll::Ready(value.into_response()),
-                ))),
-                Poll::Pending => Ok(Responder(DynResponder::new_cell::<F>(Poll::Pending)?)),
+    macro_rules! mixed_tuples {
+        ($(($d:ident, $a:ident, $i:tt)),+) => {
+            impl<$($d: MixedPart),+> MixedContainer for ($($d),+,) {
+                type Response = ($(<$d as Respond>::Type),+,);
+                type Output<'u> = ($(<$d as Output<'u, $d>>::Type),+,);
+
+                fn from_response<'u>(
+                    response: Self::Response,
+                    value_chain: &'u ValueChain,
+                ) -> Self::Output<'u> {
+                    ($($d::from_response(response.$i, value_chain)),+,)
+                }
+
+                fn try_from_borrowed_response<'u>(
+                    response: &'u Self::Response,
+                ) -> OutputResult<Self::Output<'u>> {
+                    Ok(($($d::try_from_borrowed_response(&response.$i)?),+,))
+                }
+            }
+
+            impl<$($a),+, $($d: FromMixedValue<$a>),+> MixedFrom<($($a),+,)> for ($($d),+,) {
+                fn from_value(value: ($($a),+,)) -> ($(<$d as Respond>::Type),+,) {
+                    ($($d::from_mixed_value(value.$i)),+,)
+                }
             }
+        };
+    }
+
+    mixed_tuples!((D0, A0, 0));
+    mixed_tuples!((D0, A0, 0), (D1, A1, 1));
+    mixed_tuples!((D0, A0, 0), (D1, A1, 1), (D2, A2, 2));
+    mixed_tuples!((D0, A0, 0), (D1, A1, 1), (D2, A2, 2), (D3, A3, 3));
+
+    // Mutable borrows are supported in containers with at most one borrowed value at a time.
+    impl<'u, D: MixedPart + OutputMut<'u>> OutputMut<'u> for Mixed<Option<D>> {
+        type Type = Option<<D as OutputMut<'u>>::Type>;
+
+        fn from_response_mut(
+            response: Option<<D as Respond>::Type>,
+            value_chain: &'u mut ValueChain,
+        ) -> <Self as OutputMut<'u>>::Type {
+            response.map(|value| D::from_response_mut(value, value_chain))
         }
     }
 
-    impl<'u, T, A> Output<'u, Mix<T>> for Mixed<Poll<A>>
+    impl<'u, D, E> OutputMut<'u> for Mixed<Result<D, E>>
     where
-        Mixed<T>: Respond,
-        <Mixed<T> as Respond>::Type: 'static + Send + Sync,
-        Mixed<A>: Output<'u, Mixed<T>>,
+        D: MixedPart + OutputMut<'u>,
+        E: MixedPart + OutputMut<'u>,
     {
-        type Type = Poll<<Mixed<A> as Output<'u, Mixed<T>>>::Type>;
+        type Type = Result<<D as OutputMut<'u>>::Type, <E as OutputMut<'u>>::Type>;
 
-        fn from_response(
-            response: <Mix<T> as Respond>::Type,
-            value_chain: &'u ValueChain,
-        ) -> Self::Type {
+        fn from_response_mut(
+            response: Result<<D as Respond>::Type, <E as Respond>::Type>,
+            value_chain: &'u mut ValueChain,
+        ) -> <Self as OutputMut<'u>>::Type {
             match response {
-                Poll::Ready(value) => Poll::Ready(
-                    <Mixed<A> as Output<'u, Mixed<T>>>::from_response(value, value_chain),
-                ),
-                Poll::Pending => Poll::Pending,
+                Ok(value) => Ok(D::from_response_mut(value, value_chain)),
+                Err(error) => Err(E::from_response_mut(error, value_chain)),
             }
         }
+    }
 
-        fn try_from_borrowed_response(
-            response: &'u <Mix<T> as Respond>::Type,
-        ) -> OutputResult<Self::Type> {
-            Ok(match response {
-                Poll::Ready(value) => Poll::Ready(
-                    <Mixed<A> as Output<'u, Mixed<T>>>::try_from_borrowed_response(value)?,
-                ),
-                Poll::Pending => Poll::Pending,
-            })
+    impl<'u, D: MixedPart + OutputMut<'u>> OutputMut<'u> for Mixed<Poll<D>> {
+        type Type = Poll<<D as OutputMut<'u>>::Type>;
+
+        fn from_response_mut(
+            response: Poll<<D as Respond>::Type>,
+            value_chain: &'u mut ValueChain,
+        ) -> <Self as OutputMut<'u>>::Type {
+            response.map(|value| D::from_response_mut(value, value_chain))
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::call_pattern::InputIndex;
 use crate::debug;
 use crate::mismatch::{Mismatch, MismatchKind};
-use crate::output::Output;
+use crate::output::{Output, OutputMut, Respond};
 use crate::{call_pattern::MatchingFn, *};
 
 use lib::{Box, String, Vec};
@@ -43,6 +43,7 @@ pub struct Matching<F: MockFn> {
     pub(crate) mock_fn: core::marker::PhantomData<F>,
     pub(crate) matching_fn: Option<MatchingFn<F>>,
     pub(crate) matcher_debug: Option<debug::InputMatcherDebug>,
+    pub(crate) catch_all: bool,
 }
 
 impl<F> Matching<F>
@@ -54,6 +55,7 @@ where
             mock_fn: core::marker::PhantomData,
             matching_fn: None,
             matcher_debug: None,
+            catch_all: false,
         }
     }
 
@@ -66,6 +68,22 @@ where
     pub fn func<M>(&mut self, matching_fn: M)
     where
         M: (for<'i> Fn(&F::Inputs<'i>, &mut MismatchReporter) -> bool) + Send + Sync + 'static,
+    {
+        self.matching_fn = Some(MatchingFn(Box::new(move |inputs, _mutation, reporter| {
+            matching_fn(inputs, reporter)
+        })));
+    }
+
+    /// Set the matching function, which also receives a shared reference to the [Mutation](MockFn::Mutation), with debug capabilities.
+    ///
+    /// This makes it possible to constrain the state of the mutated argument at the time of the call.
+    #[inline]
+    pub fn mutation_func<M>(&mut self, matching_fn: M)
+    where
+        M: (for<'i, 'm> Fn(&F::Inputs<'i>, &F::Mutation<'m>, &mut MismatchReporter) -> bool)
+            + Send
+            + Sync
+            + 'static,
     {
         self.matching_fn = Some(MatchingFn(Box::new(matching_fn)));
     }
@@ -80,6 +98,13 @@ where
             line,
         });
     }
+
+    /// Declare that the matcher accepts any inputs.
+    ///
+    /// Unimock uses this to detect later call patterns that can never be matched.
+    pub fn catch_all(&mut self) {
+        self.catch_all = true;
+    }
 }
 
 /// A reporter used in call pattern matchers in case of mismatched inputs.
@@ -89,24 +114,67 @@ where
 /// Used by the [matching] macro.
 pub struct MismatchReporter {
     enabled: bool,
+    redacted_inputs: debug::RedactedInputs,
     pub(crate) mismatches: Vec<(InputIndex, Mismatch)>,
+    #[cfg(feature = "std")]
+    pending_snapshots: Vec<crate::snapshot::PendingWrite>,
 }
 
 impl MismatchReporter {
-    pub(crate) fn new_enabled() -> Self {
+    pub(crate) fn new_enabled(redacted_inputs: debug::RedactedInputs) -> Self {
         Self {
             enabled: true,
+            redacted_inputs,
             mismatches: lib::vec![],
+            #[cfg(feature = "std")]
+            pending_snapshots: lib::vec![],
         }
     }
 
     pub(crate) fn new_disabled() -> Self {
         Self {
             enabled: false,
+            redacted_inputs: debug::RedactedInputs::NONE,
             mismatches: lib::vec![],
+            #[cfg(feature = "std")]
+            pending_snapshots: lib::vec![],
         }
     }
 
+    /// Write the snapshots recorded while matching, after the call pattern has been selected.
+    pub(crate) fn commit_snapshots(self) {
+        #[cfg(feature = "std")]
+        for pending in self.pending_snapshots {
+            pending.commit();
+        }
+    }
+
+    fn push(
+        &mut self,
+        input_index: usize,
+        kind: MismatchKind,
+        actual: Option<String>,
+        expected: Option<String>,
+    ) {
+        let mismatch = if self.redacted_inputs.contains(input_index) {
+            Mismatch {
+                kind,
+                actual: None,
+                expected: None,
+                redacted: true,
+            }
+        } else {
+            Mismatch {
+                kind,
+                actual,
+                expected,
+                redacted: false,
+            }
+        };
+
+        self.mismatches.push((InputIndex(input_index), mismatch));
+    }
+
     /// Whether debugging is enabled
     pub fn enabled(&self) -> bool {
         self.enabled
@@ -119,14 +187,12 @@ impl MismatchReporter {
         actual: Option<impl Into<String>>,
         expected: Option<impl Into<String>>,
     ) {
-        self.mismatches.push((
-            InputIndex(input_index),
-            Mismatch {
-                kind: MismatchKind::Pattern,
-                actual: actual.map(|dbg| dbg.into()),
-                expected: expected.map(|dbg| dbg.into()),
-            },
-        ));
+        self.push(
+            input_index,
+            MismatchKind::Pattern,
+            actual.map(|dbg| dbg.into()),
+            expected.map(|dbg| dbg.into()),
+        );
     }
 
     /// Register failure to match a pattern
@@ -136,14 +202,27 @@ impl MismatchReporter {
         actual: Option<impl Into<String>>,
         expected: Option<impl Into<String>>,
     ) {
-        self.mismatches.push((
-            InputIndex(input_index),
-            Mismatch {
-                kind: MismatchKind::Eq,
-                actual: actual.map(|dbg| dbg.into()),
-                expected: expected.map(|dbg| dbg.into()),
-            },
-        ));
+        self.push(
+            input_index,
+            MismatchKind::Eq,
+            actual.map(|dbg| dbg.into()),
+            expected.map(|dbg| dbg.into()),
+        );
+    }
+
+    /// Register failure to match a snapshot
+    pub fn snapshot_fail(
+        &mut self,
+        input_index: usize,
+        actual: Option<impl Into<String>>,
+        expected: Option<impl Into<String>>,
+    ) {
+        self.push(
+            input_index,
+            MismatchKind::Snapshot,
+            actual.map(|dbg| dbg.into()),
+            expected.map(|dbg| dbg.into()),
+        );
     }
 
     /// Register failure for an ne check
@@ -153,14 +232,12 @@ impl MismatchReporter {
         actual: Option<impl Into<String>>,
         expected: Option<impl Into<String>>,
     ) {
-        self.mismatches.push((
-            InputIndex(input_index),
-            Mismatch {
-                kind: MismatchKind::Ne,
-                actual: actual.map(|dbg| dbg.into()),
-                expected: expected.map(|dbg| dbg.into()),
-            },
-        ));
+        self.push(
+            input_index,
+            MismatchKind::Ne,
+            actual.map(|dbg| dbg.into()),
+            expected.map(|dbg| dbg.into()),
+        );
     }
 }
 
@@ -177,6 +254,18 @@ where
     unimock.handle_error(eval::eval(unimock, inputs, mutation))
 }
 
+/// Convert the evaluated response of a `&mut self` method into an output that borrows mutably from the instance.
+pub fn output_mut<'u, F>(
+    unimock: &'u mut Unimock,
+    response: <F::Response as Respond>::Type,
+) -> <F::Response as OutputMut<'u>>::Type
+where
+    F: MockFn,
+    F::Response: OutputMut<'u>,
+{
+    <F::Response as OutputMut<'u>>::from_response_mut(response, &mut unimock.value_chain)
+}
+
 /// Clone a Unimock instance
 pub fn clone_unimock(unimock: &Unimock) -> Unimock {
     unimock.clone()
@@ -209,11 +298,89 @@ impl<T> NoDebug for &T {
     }
 }
 
+/// Format a debug representation using a custom formatting function.
+///
+/// Used for arguments annotated with `#[unimock(debug_with = ..)]`.
+pub fn debug_with<T: ?Sized, D>(value: &T, debug_fn: D) -> Option<String>
+where
+    D: Fn(&T, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+{
+    struct DebugWith<'t, T: ?Sized, D>(&'t T, D);
+
+    impl<'t, T: ?Sized, D> core::fmt::Debug for DebugWith<'t, T, D>
+    where
+        D: Fn(&T, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            (self.1)(self.0, f)
+        }
+    }
+
+    Some(lib::format!("{:?}", DebugWith(value, debug_fn)))
+}
+
+/// The debug representation of a redacted argument.
+///
+/// Used for arguments annotated with `#[unimock(redact)]`.
+pub fn redacted_debug<T: ?Sized>(_value: &T) -> Option<String> {
+    Some(String::from("<redacted>"))
+}
+
+/// Compare the pretty-printed debug representation of a value to a snapshot file.
+///
+/// A snapshot that needs to be written is recorded in the reporter,
+/// and only gets written if the call pattern is selected.
+///
+/// Used by `snapshot!` in the [matching] macro.
+#[cfg(feature = "std")]
+pub fn snapshot_matches<T: core::fmt::Debug + ?Sized>(
+    reporter: &mut MismatchReporter,
+    manifest_dir: &str,
+    name: &str,
+    value: &T,
+) -> bool {
+    match crate::snapshot::compare(manifest_dir, name, snapshot_debug(value)) {
+        crate::snapshot::Comparison::Equal => true,
+        crate::snapshot::Comparison::NotEqual => false,
+        crate::snapshot::Comparison::Pending(pending) => {
+            reporter.pending_snapshots.push(pending);
+            true
+        }
+    }
+}
+
+/// Discard snapshot writes recorded by a previous, failed arm of a [matching] pattern.
+///
+/// Always returns `true`, so it can be chained in front of the arm's guard.
+#[cfg(feature = "std")]
+pub fn snapshot_arm(reporter: &mut MismatchReporter) -> bool {
+    reporter.pending_snapshots.clear();
+    true
+}
+
+/// Store the snapshots of `snapshot!` in `root` instead of `tests/snapshots` while running `f` on the current thread.
+#[cfg(feature = "std")]
+pub fn with_snapshot_root<R>(root: &std::path::Path, f: impl FnOnce() -> R) -> R {
+    crate::snapshot::with_root(root, f)
+}
+
+/// The representation of a value that gets stored in a snapshot file.
+#[cfg(feature = "std")]
+pub fn snapshot_debug<T: core::fmt::Debug + ?Sized>(value: &T) -> String {
+    lib::format!("{value:#?}")
+}
+
+/// Read the contents of a snapshot file.
+#[cfg(feature = "std")]
+pub fn snapshot_expected(manifest_dir: &str, name: &str) -> Option<String> {
+    crate::snapshot::read(manifest_dir, name).map(|expected| String::from(expected.trim_end()))
+}
+
 /// Convert any type implementing `AsRef<str>` to a `&str`.
 #[inline]
 pub fn as_str_ref<T>(input: &T) -> &str
 where
-    T: AsRef<str>,
+    T: ?Sized + AsRef<str>,
 {
     input.as_ref()
 }
@@ -222,11 +389,27 @@ where
 #[inline]
 pub fn as_slice<T, I>(input: &T) -> &[I]
 where
-    T: AsRef<[I]>,
+    T: ?Sized + AsRef<[I]>,
 {
     input.as_ref()
 }
 
+/// Trait objects of mocked traits, which an implementor `U` can be boxed into.
+///
+/// Implemented by the `unimock` macro for `dyn Trait`, `dyn Trait + Send` and `dyn Trait + Send + Sync` with `dyn_mock=true`.
+pub trait DynMock<U> {
+    fn boxed(value: U) -> Box<Self>;
+}
+
+/// Resolves the error type of a `Result` type alias like `io::Result<T>`, in mixed outputs.
+pub trait ResultAlias {
+    type Error;
+}
+
+impl<T, E> ResultAlias for Result<T, E> {
+    type Error = E;
+}
+
 /// Shorthand for converting any `T: AsRef<U>` to `&U`
 #[inline]
 pub fn as_ref<T, U>(input: &T) -> &U
@@ -248,10 +431,12 @@ where
 /// Standard library re-exports when using `std`.
 #[cfg(feature = "std")]
 pub mod lib {
+    pub use ::std::borrow::{Cow, ToOwned};
     pub use ::std::boxed::Box;
     pub use ::std::collections::btree_map::Entry;
     pub use ::std::collections::BTreeMap;
     pub use ::std::collections::BTreeSet;
+    pub use ::std::collections::VecDeque;
     pub use ::std::format;
     pub use ::std::rc::Rc;
     pub use ::std::string::String;
@@ -264,10 +449,12 @@ pub mod lib {
 /// Standard library re-exports when using `no_std`.
 #[cfg(not(feature = "std"))]
 pub mod lib {
+    pub use ::alloc::borrow::{Cow, ToOwned};
     pub use ::alloc::boxed::Box;
     pub use ::alloc::collections::btree_map::Entry;
     pub use ::alloc::collections::BTreeMap;
     pub use ::alloc::collections::BTreeSet;
+    pub use ::alloc::collections::VecDeque;
     pub use ::alloc::format;
     pub use ::alloc::rc::Rc;
     pub use ::alloc::string::String;
@@ -0,0 +1,86 @@
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::string::String;
+
+/// Setting this environment variable (re)writes the snapshots of the call patterns that get selected.
+const UPDATE_ENV_VAR: &str = "UNIMOCK_UPDATE_SNAPSHOTS";
+
+std::thread_local! {
+    // Replaces `tests/snapshots` of the crate being tested, for the current thread.
+    static ROOT_OVERRIDE: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with snapshots stored in `root` instead of the `tests/snapshots` directory of the crate.
+pub(crate) fn with_root<R>(root: &Path, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<PathBuf>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            ROOT_OVERRIDE.with(|root| *root.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(ROOT_OVERRIDE.with(|cell| cell.replace(Some(root.to_path_buf()))));
+    f()
+}
+
+fn snapshot_path(manifest_dir: &str, name: &str) -> PathBuf {
+    let root = ROOT_OVERRIDE
+        .with(|root| root.borrow().clone())
+        .unwrap_or_else(|| Path::new(manifest_dir).join("tests").join("snapshots"));
+
+    root.join(format!("{name}.snap"))
+}
+
+pub(crate) fn read(manifest_dir: &str, name: &str) -> Option<String> {
+    std::fs::read_to_string(snapshot_path(manifest_dir, name)).ok()
+}
+
+/// The outcome of comparing a value to a stored snapshot.
+pub(crate) enum Comparison {
+    Equal,
+    NotEqual,
+    /// The comparison succeeds once the snapshot is written.
+    Pending(PendingWrite),
+}
+
+/// A snapshot write that is deferred until its call pattern has been selected.
+pub(crate) struct PendingWrite {
+    path: PathBuf,
+    actual: String,
+}
+
+impl PendingWrite {
+    pub(crate) fn commit(self) {
+        write(&self.path, &self.actual);
+    }
+}
+
+/// Compare the actual value to the stored snapshot, without touching the file system other than reading.
+///
+/// The comparison is pending a write when the snapshot file does not yet exist
+/// or the update environment variable is set.
+pub(crate) fn compare(manifest_dir: &str, name: &str, actual: String) -> Comparison {
+    let path = snapshot_path(manifest_dir, name);
+
+    if std::env::var_os(UPDATE_ENV_VAR).is_some() || !path.exists() {
+        return Comparison::Pending(PendingWrite { path, actual });
+    }
+
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if expected.trim_end() == actual.trim_end() => Comparison::Equal,
+        _ => Comparison::NotEqual,
+    }
+}
+
+fn write(path: &Path, actual: &str) {
+    let result = path
+        .parent()
+        .map(std::fs::create_dir_all)
+        .unwrap_or(Ok(()))
+        .and_then(|_| std::fs::write(path, format!("{actual}\n")));
+
+    if let Err(err) = result {
+        panic!("Could not write snapshot {}: {err}", path.display());
+    }
+}
@@ -1,6 +1,7 @@
 use core::any::TypeId;
 use core::sync::atomic::AtomicUsize;
 
+use crate::call_pattern::Scope;
 use crate::debug;
 use crate::error;
 use crate::fn_mocker::{FnMocker, PatternMatchMode};
@@ -11,6 +12,7 @@ use crate::FallbackMode;
 pub(crate) struct SharedState {
     pub fallback_mode: FallbackMode,
     pub fn_mockers: BTreeMap<TypeId, FnMocker>,
+    scope_parents: Vec<Scope>,
 
     #[cfg(feature = "std")]
     pub original_thread: std::thread::ThreadId,
@@ -20,10 +22,15 @@ pub(crate) struct SharedState {
 }
 
 impl SharedState {
-    pub fn new(fn_mockers: BTreeMap<TypeId, FnMocker>, fallback_mode: FallbackMode) -> Self {
+    pub fn new(
+        fn_mockers: BTreeMap<TypeId, FnMocker>,
+        scope_parents: Vec<Scope>,
+        fallback_mode: FallbackMode,
+    ) -> Self {
         Self {
             fallback_mode,
             fn_mockers,
+            scope_parents,
 
             #[cfg(feature = "std")]
             original_thread: std::thread::current().id(),
@@ -33,6 +40,19 @@ impl SharedState {
         }
     }
 
+    /// Find the closest scope, starting at `scope` and moving towards the root, that has call patterns for the given `FnMocker`.
+    pub fn find_pattern_scope(&self, fn_mocker: &FnMocker, mut scope: Scope) -> Option<Scope> {
+        loop {
+            if fn_mocker.has_scope(scope) {
+                return Some(scope);
+            }
+            if scope == Scope::ROOT {
+                return None;
+            }
+            scope = self.scope_parents[scope.0];
+        }
+    }
+
     pub fn bump_ordered_call_index(&self) -> usize {
         self.next_ordered_call_index
             .fetch_add(1, core::sync::atomic::Ordering::SeqCst)
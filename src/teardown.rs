@@ -14,11 +14,19 @@ pub(crate) fn teardown_panic(unimock: &mut Unimock) {
 
 #[cfg(feature = "std")]
 pub(crate) fn teardown_report(unimock: &mut Unimock) -> std::process::ExitCode {
+    teardown_report_with(unimock, |error| eprintln!("unimock MockError: {error}"))
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn teardown_report_with(
+    unimock: &mut Unimock,
+    mut report_error: impl FnMut(&MockError),
+) -> std::process::ExitCode {
     match teardown(unimock) {
         Ok(()) => std::process::ExitCode::SUCCESS,
         Err(errors) => {
-            for error in errors {
-                eprintln!("unimock MockError: {error}");
+            for error in &errors {
+                report_error(error);
             }
             std::process::ExitCode::FAILURE
         }
@@ -74,7 +82,7 @@ pub(crate) fn teardown(unimock: &mut Unimock) -> Result<(), Vec<MockError>> {
     }
 
     let mut mock_errors = Vec::new();
-    for (_, fn_mocker) in unimock.shared_state.fn_mockers.iter() {
+    for fn_mocker in unimock.shared_state.fn_mockers.values() {
         fn_mocker.verify(&mut mock_errors);
     }
 
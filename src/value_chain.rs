@@ -18,6 +18,24 @@ impl ValueChain {
         node.value.as_ref().downcast_ref::<T>().unwrap()
     }
 
+    /// Add a value and borrow it mutably.
+    ///
+    /// This requires exclusive access to the chain, so no other value is borrowed at the same time.
+    pub fn add_mut<T: Any + Send + Sync>(&mut self, value: T) -> &mut T {
+        let mut cell = &mut self.root_node;
+        while cell.get().is_some() {
+            cell = &mut cell.get_mut().unwrap().next;
+        }
+
+        let _ = cell.set(Node::new(value));
+        cell.get_mut()
+            .unwrap()
+            .value
+            .as_mut()
+            .downcast_mut::<T>()
+            .unwrap()
+    }
+
     fn push_node(&self, mut new_node: Node) -> &Node {
         let mut cell = &self.root_node;
         loop {
@@ -73,3 +91,13 @@ fn it_works() {
     assert_eq!(&"", second);
     assert_eq!(&42.0, third);
 }
+
+#[test]
+fn add_mut_works() {
+    let mut value_chain = ValueChain::default();
+    value_chain.add(1);
+    let second = value_chain.add_mut(2);
+    *second += 1;
+    assert_eq!(&mut 3, second);
+    assert_eq!(&mut "", value_chain.add_mut(""));
+}
@@ -3,6 +3,10 @@ set -e
 set -x
 
 (cd unimock_macros; cargo test)
-cargo hack --feature-powerset --features std --exclude-features default,spin-lock,nightly-tests,unstable-doc-cfg test
-cargo hack --feature-powerset --features critical-section --exclude-features std,mock-std,default,nightly-tests,unstable-doc-cfg test
-cargo test --doc --features mock-core,mock-std
+cargo hack --feature-powerset --features std --group-features mock-tokio,mock-futures,mock-serde --exclude-features default,spin-lock,nightly-tests,unstable-doc-cfg test
+cargo check --all-targets --features mock-tokio
+cargo check --all-targets --features mock-futures
+cargo check --all-targets --features mock-serde
+cargo hack --feature-powerset --features critical-section --exclude-features std,mock-std,mock-tokio,mock-futures,mock-serde,default,nightly-tests,unstable-doc-cfg test
+cargo test --doc --features mock-core,mock-std,mock-tokio,mock-futures,mock-serde
+cargo clippy --all-targets --features mock-core,mock-std,mock-tokio,mock-futures,mock-serde -- -D warnings
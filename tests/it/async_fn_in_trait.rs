@@ -0,0 +1,46 @@
+use unimock::*;
+
+#[unimock(api = TraitMock)]
+trait Trait {
+    async fn a(&self, arg: i32) -> i32;
+    async fn b(&self) -> &i32;
+    async fn c(&self) -> Option<&i32>;
+}
+
+#[tokio::test]
+async fn test_it() {
+    let deps = Unimock::new((
+        TraitMock::a.next_call(matching!(_)).returns(42),
+        TraitMock::b.next_call(matching!()).returns(42),
+        TraitMock::c.next_call(matching!()).returns(Some(42)),
+    ));
+
+    assert_eq!(42, deps.a(5).await);
+    assert_eq!(&42, deps.b().await);
+    assert_eq!(Some(&42), deps.c().await);
+}
+
+#[unimock(api = MoreMock)]
+trait More {
+    async fn borrow_arg(&self, s: &str) -> String;
+    async fn mut_self(&mut self) -> i32;
+    async fn with_default(&self) -> i32 {
+        1
+    }
+}
+
+#[tokio::test]
+async fn more() {
+    let mut u = Unimock::new((
+        MoreMock::borrow_arg
+            .next_call(matching!("a"))
+            .returns("b".to_string()),
+        MoreMock::mut_self.next_call(matching!()).returns(2),
+        MoreMock::with_default
+            .next_call(matching!())
+            .default_implementation(),
+    ));
+    assert_eq!("b", u.borrow_arg("a").await);
+    assert_eq!(2, u.mut_self().await);
+    assert_eq!(1, u.with_default().await);
+}
@@ -30,6 +30,7 @@ mod trailing_comma_in_args {
     type EnourmoslyLongTypeThatCausesRustfmtToBreakFnArgsIntoMultipleLines = i32;
 
     // Regression test: trailing comma after single argument (tupling problems)
+    #[allow(dead_code)]
     #[unimock]
     trait NoArg {
         fn trailing_comma(
@@ -91,6 +92,7 @@ mod exotic_self_types {
     use core::pin::Pin;
     use std::rc::Rc;
 
+    #[allow(dead_code)]
     #[unimock]
     trait OwnedSelf {
         fn foo(self);
@@ -138,6 +140,7 @@ mod exotic_self_types {
         assert_eq!(42, Pin::new(&mut deps).pin_mut_self());
     }
 
+    #[allow(dead_code)]
     #[unimock(api=PinMutSelfBorrowMock)]
     trait PinMutBorrowSelf {
         fn pin_mut_self_borrow(self: Pin<&mut Self>) -> Option<&i32>;
@@ -161,6 +164,7 @@ mod exotic_methods {
         assert_eq!(42, deps.provided());
     }
 
+    #[allow(dead_code)]
     #[unimock]
     trait SkipStaticProvided {
         fn skip1() {}
@@ -173,6 +177,7 @@ mod exotic_methods {
 mod referenced {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock(api=ReferencedMock)]
     trait Referenced {
         fn foo(&self, a: &str) -> &str;
@@ -330,16 +335,19 @@ fn should_debug_reference_to_debug_implementing_type() {
     #[derive(Debug)]
     pub enum DebugEnum {}
 
+    #[allow(dead_code)]
     #[unimock]
     trait VeryPrimitiveRefZero {
         fn primitive_ref(&self, a: DebugEnum) -> DebugEnum;
     }
 
+    #[allow(dead_code)]
     #[unimock]
     trait VeryPrimitiveRefOnce {
         fn primitive_ref(&self, a: &DebugEnum) -> DebugEnum;
     }
 
+    #[allow(dead_code)]
     #[unimock]
     trait VeryPrimitiveRefTwice {
         fn primitive_ref(&self, a: &&DebugEnum) -> DebugEnum;
@@ -430,13 +438,14 @@ mod custom_api_module {
 
     pub struct MyType;
 
+    #[allow(dead_code)]
     #[unimock(api=FakeSingle)]
     trait Single {
         fn func(&self) -> &MyType;
     }
 
     #[test]
-    #[should_panic = "Single::func: Expected Single::func(_) at tests/it/basic.rs:443 to match exactly 1 call, but it actually matched no calls.\nMock for Single::func was never called. Dead mocks should be removed."]
+    #[should_panic = "Single::func: Expected Single::func(_) at tests/it/basic.rs:452 to match exactly 1 call, but it actually matched no calls.\nMock for Single::func was never called. Dead mocks should be removed."]
     fn test_without_module() {
         Unimock::new(
             FakeSingle::func
@@ -452,6 +461,7 @@ mod flattened_module {
         use unimock::private::lib::String;
         use unimock::*;
 
+        #[allow(dead_code)]
         #[unimock(api=[Foo, Bar])]
         trait WithUnpackedModule {
             fn foo(&self, input: String) -> i32;
@@ -469,6 +479,7 @@ mod flattened_module {
         use unimock::private::lib::String;
         use unimock::*;
 
+        #[allow(dead_code)]
         #[unimock(api=[Foo, Bar])]
         trait UnpackedGenerics<T> {
             fn foo(&self, input: String) -> T;
@@ -479,6 +490,7 @@ mod flattened_module {
     mod exports {
         mod inner {
             use unimock::*;
+            #[allow(dead_code)]
             #[unimock(api=[FooMock])]
             pub trait Trait {
                 fn foo(&self);
@@ -589,6 +601,7 @@ fn borrow_intricate_lifetimes() {
     use unimock::private::lib::{Box, String};
 
     pub struct I<'s>(core::marker::PhantomData<&'s ()>);
+    #[allow(dead_code)]
     pub struct O<'s>(&'s String);
 
     #[unimock(api = IntricateMock)]
@@ -676,7 +689,7 @@ mod responders_in_series {
 
     #[test]
     #[should_panic(
-        expected = "Series::series: Expected Series::series() at tests/it/basic.rs:652 to match at least 4 calls, but it actually matched 2 calls."
+        expected = "Series::series: Expected Series::series() at tests/it/basic.rs:665 to match at least 4 calls, but it actually matched 2 calls."
     )]
     fn series_not_fully_generated_should_panic() {
         let b = Unimock::new(clause());
@@ -748,6 +761,7 @@ mod lifetime_constrained_output_type {
     #[derive(Clone)]
     pub struct Borrowing2<'a, 'b>(&'a str, &'b str);
 
+    #[allow(dead_code)]
     #[unimock(api=BorrowSyncMock)]
     trait BorrowSync {
         fn borrow_sync_elided(&self) -> Borrowing1<'_>;
@@ -755,6 +769,7 @@ mod lifetime_constrained_output_type {
         fn borrow_sync_explicit2<'a, 'b>(&'a self, arg: &'b str) -> Borrowing2<'a, 'b>;
     }
 
+    #[allow(dead_code)]
     #[cfg(feature = "std")]
     #[unimock]
     #[::async_trait::async_trait]
@@ -832,6 +847,7 @@ mod slice_matching {
 
 #[test]
 fn eval_name_clash() {
+    #[allow(dead_code)]
     #[unimock(api = Mock, unmock_with=[unmock])]
     trait Trait {
         fn tralala(&self, eval: i32);
@@ -846,7 +862,7 @@ fn fn_cfg_attrs() {
     trait Trait {
         fn a(&self) -> i32;
 
-        #[cfg(feature = "always-disabled")]
+        #[cfg(any())]
         fn b(&self) -> NonExistentType;
     }
 
@@ -980,6 +996,7 @@ mod borrow_dyn {
 mod associated_type {
     use unimock::*;
 
+    #[allow(dead_code)]
     #[unimock(api = AssocMock, type Foo = i32; type Bar = i32;)]
     pub trait Assoc {
         type Foo;
@@ -996,6 +1013,7 @@ mod associated_type {
 mod associated_const {
     use unimock::*;
 
+    #[allow(dead_code)]
     #[unimock(api = AssocConstMock, const FOO: i32 = 42;)]
     pub trait Assoc {
         const FOO: i32;
@@ -1005,6 +1023,7 @@ mod associated_const {
 mod associated_type_and_const {
     use unimock::*;
 
+    #[allow(dead_code)]
     #[unimock(api = AssocMock, type Foo = i32; const FOO: &'static str = "it works!"; type Bar = i32; const BAR: bool = true;)]
     pub trait Assoc {
         type Foo;
@@ -1024,6 +1043,7 @@ mod associated_type_and_const {
 mod no_verify_in_drop {
     use unimock::*;
 
+    #[allow(dead_code)]
     #[unimock(api = TraitMock)]
     trait Trait {
         fn foo(&self);
@@ -1063,6 +1083,7 @@ mod debug_mut_arg {
 
     // A bug where we need `(&*arg).unimock_try_debug();`
     // instead of `(*arg).unimock_try_debug()`
+    #[allow(dead_code)]
     #[unimock(api = TestMock)]
     trait Test {
         fn f(&self, arg1: &mut Arg, arg2: &mut Arg);
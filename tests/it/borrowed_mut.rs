@@ -0,0 +1,121 @@
+use core::pin::Pin;
+
+use unimock::private::lib::{vec, String, ToString, Vec};
+use unimock::*;
+
+#[unimock(api = BufferMock)]
+trait Buffer {
+    fn buffer_mut(&mut self) -> &mut Vec<u8>;
+    fn text_mut(&mut self) -> &mut str;
+    fn get_mut(&mut self, key: &str) -> Option<&mut String>;
+    fn try_get_mut(&mut self) -> Result<&mut i32, String>;
+    fn pinned_mut(self: Pin<&mut Self>) -> &mut i32;
+
+    fn first_mut(&mut self) -> Option<&mut u8> {
+        self.buffer_mut().first_mut()
+    }
+}
+
+#[test]
+fn returns_mutable_reference() {
+    let mut u = Unimock::new(
+        BufferMock::buffer_mut
+            .next_call(matching!())
+            .returns(vec![1, 2]),
+    );
+
+    let buffer = u.buffer_mut();
+    buffer.push(3);
+    assert_eq!(&[1, 2, 3], buffer.as_slice());
+}
+
+#[test]
+fn unsized_mutable_reference() {
+    let mut u = Unimock::new(
+        BufferMock::text_mut
+            .next_call(matching!())
+            .returns("abc".to_string()),
+    );
+
+    let text = u.text_mut();
+    text.make_ascii_uppercase();
+    assert_eq!("ABC", text);
+}
+
+#[test]
+fn each_call_borrows_a_fresh_clone() {
+    let mut u = Unimock::new(
+        BufferMock::buffer_mut
+            .each_call(matching!())
+            .returns(vec![1]),
+    );
+
+    u.buffer_mut().push(2);
+    assert_eq!(&vec![1], u.buffer_mut());
+}
+
+#[test]
+fn mixed_mutable_references() {
+    let mut u = Unimock::new((
+        BufferMock::get_mut
+            .next_call(matching!("a"))
+            .returns(Some("value".to_string())),
+        BufferMock::get_mut
+            .next_call(matching!("b"))
+            .returns(None::<String>),
+        BufferMock::try_get_mut
+            .next_call(matching!())
+            .answers(|_| Ok(1)),
+        BufferMock::try_get_mut
+            .next_call(matching!())
+            .returns(Err::<i32, _>("error".to_string())),
+    ));
+
+    let value = u.get_mut("a").unwrap();
+    value.push('!');
+    assert_eq!("value!", value);
+    assert_eq!(None, u.get_mut("b"));
+
+    *u.try_get_mut().unwrap() += 1;
+    assert_eq!(Err("error".to_string()), u.try_get_mut());
+}
+
+#[test]
+fn pinned_receiver() {
+    let mut u = Unimock::new(BufferMock::pinned_mut.next_call(matching!()).returns(1));
+
+    let value = Pin::new(&mut u).pinned_mut();
+    *value += 1;
+    assert_eq!(2, *value);
+}
+
+#[test]
+fn default_implementation() {
+    let mut u = Unimock::new((
+        BufferMock::first_mut
+            .next_call(matching!())
+            .default_implementation(),
+        BufferMock::buffer_mut
+            .next_call(matching!())
+            .returns(vec![1, 2]),
+    ));
+
+    let first = u.first_mut().unwrap();
+    *first = 42;
+    assert_eq!(42, *first);
+}
+
+#[unimock(api = CounterMock, unmock_with=[static_counter])]
+trait Counter {
+    fn counter_mut(&mut self) -> &mut i32;
+}
+
+fn static_counter(_: &mut impl core::any::Any) -> &mut i32 {
+    unimock::private::lib::Box::leak(unimock::private::lib::Box::new(0))
+}
+
+#[test]
+fn unmocked() {
+    let mut u = Unimock::new(CounterMock::counter_mut.next_call(matching!()).unmocked());
+    *u.counter_mut() += 1;
+}
@@ -0,0 +1,65 @@
+use unimock::*;
+
+#[unimock(api = BufferMock)]
+trait Buffer<const N: usize> {
+    fn capacity(&self) -> usize;
+    fn fill(&self, byte: u8) -> [u8; N];
+}
+
+#[unimock(api = ReadArrayMock)]
+trait ReadArray {
+    fn read_array<const N: usize>(&self) -> [u8; N];
+}
+
+#[test]
+fn const_generic_trait() {
+    let u = Unimock::new((
+        BufferMock::capacity
+            .with_types::<4>()
+            .next_call(matching!())
+            .returns(4_usize),
+        BufferMock::capacity
+            .with_types::<8>()
+            .next_call(matching!())
+            .returns(8_usize),
+        BufferMock::fill
+            .with_types::<2>()
+            .next_call(matching!(1))
+            .returns([1, 1]),
+    ));
+    assert_eq!(4, Buffer::<4>::capacity(&u));
+    assert_eq!(8, Buffer::<8>::capacity(&u));
+    assert_eq!([1, 1], Buffer::<2>::fill(&u, 1));
+}
+
+#[test]
+fn const_generic_method() {
+    let u = Unimock::new((
+        ReadArrayMock::read_array
+            .with_types::<2>()
+            .each_call(matching!())
+            .returns([1, 2]),
+        ReadArrayMock::read_array
+            .with_types::<3>()
+            .each_call(matching!())
+            .returns([1, 2, 3]),
+    ));
+    assert_eq!([1, 2, 3], u.read_array::<3>());
+    assert_eq!([1, 2], u.read_array::<2>());
+}
+
+#[unimock(api = RowMock)]
+trait Row<T, const N: usize = 2> {
+    fn row<const M: usize>(&self, value: T) -> [[T; N]; M];
+}
+
+#[test]
+fn mixed_type_and_const_generics() {
+    let u = Unimock::new(
+        RowMock::row
+            .with_types::<i32, 2, 1>()
+            .next_call(matching!(7))
+            .returns([[7, 7]]),
+    );
+    assert_eq!([[7, 7]], <Unimock as Row<i32>>::row::<1>(&u, 7));
+}
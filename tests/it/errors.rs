@@ -1,6 +1,7 @@
 use unimock::private::lib::String;
 use unimock::*;
 
+#[allow(dead_code)]
 #[unimock(api=SingleArgMock)]
 trait SingleArg {
     fn method1<'s>(&'s self, a: &'s str) -> &'s str;
@@ -46,7 +47,7 @@ fn should_panic_for_empty_stub_closure() {
 
 #[test]
 #[should_panic(
-    expected = "SingleArg::method1(\"whatever\"): No output available for after matching SingleArg::method1(_) at tests/it/errors.rs:53."
+    expected = "SingleArg::method1(\"whatever\"): No output available for after matching SingleArg::method1(_) at tests/it/errors.rs:54."
 )]
 fn call_pattern_without_output_factory_should_crash() {
     Unimock::new(SingleArgMock::method1.stub(|each| {
@@ -66,7 +67,7 @@ fn should_panic_if_no_call_patterns_in_stub_are_matched() {
 
 #[test]
 #[should_panic(
-    expected = "SingleArg::method1: Expected SingleArg::method1(\"a\") at tests/it/errors.rs:73 to match exactly 1 call, but it actually matched no calls."
+    expected = "SingleArg::method1: Expected SingleArg::method1(\"a\") at tests/it/errors.rs:74 to match exactly 1 call, but it actually matched no calls."
 )]
 fn call_pattern_with_count_expectation_should_panic_if_not_met() {
     Unimock::new(SingleArgMock::method1.stub(|each| {
@@ -78,7 +79,7 @@ fn call_pattern_with_count_expectation_should_panic_if_not_met() {
 
 #[test]
 #[should_panic(
-    expected = "SingleArg::method1(\"b\"): Explicit panic from SingleArg::method1(_) at tests/it/errors.rs:85: foobar!"
+    expected = "SingleArg::method1(\"b\"): Explicit panic from SingleArg::method1(_) at tests/it/errors.rs:86: foobar!"
 )]
 fn should_panic_with_explicit_message() {
     Unimock::new(SingleArgMock::method1.stub(|each| {
@@ -119,7 +120,7 @@ fn multithread_error_reporting_works() {
 #[cfg(any(feature = "std", feature = "spin-lock"))]
 #[test]
 #[should_panic(
-    expected = "Foo::foo(2): Cannot return value more than once from Foo::foo(_) at tests/it/errors.rs:130, because of missing Clone bound. Try using `.each_call()` or explicitly quantifying the response."
+    expected = "Foo::foo(2): Cannot return value more than once from Foo::foo(_) at tests/it/errors.rs:131, because of missing Clone bound. Try using `.each_call()` or explicitly quantifying the response."
 )]
 fn should_complain_when_returning_unquantified_value_more_then_once() {
     #[unimock(api=FooMock)]
@@ -136,7 +137,7 @@ fn should_complain_when_returning_unquantified_value_more_then_once() {
 #[cfg(any(feature = "std", feature = "spin-lock"))]
 #[test]
 #[should_panic(
-    expected = "Foo::foo: Expected Foo::foo(2) at tests/it/errors.rs:149 to match exactly 1 call, but it actually matched no calls."
+    expected = "Foo::foo: Expected Foo::foo(2) at tests/it/errors.rs:150 to match exactly 1 call, but it actually matched no calls."
 )]
 fn should_require_both_calls_2_some_call() {
     #[unimock(api=FooMock)]
@@ -190,3 +191,50 @@ fn no_default_impl() {
 fn should_panic_without_mutex_api_for_owned_once_responder() {
     Unimock::new(SingleArgMock::owned.next_call(matching!(_)).returns(666));
 }
+
+#[test]
+#[should_panic(
+    expected = "SingleArg::method1(\"a\") at tests/it/errors.rs:202 can never match, because it is shadowed by the catch-all SingleArg::method1(_) at tests/it/errors.rs:201 with unbounded call count."
+)]
+fn should_panic_for_call_pattern_shadowed_by_catch_all() {
+    Unimock::new(SingleArgMock::method1.stub(|each| {
+        each.call(matching!(_)).returns(String::new());
+        each.call(matching!("a")).returns(String::new());
+    }));
+}
+
+#[test]
+fn guarded_wildcard_pattern_does_not_shadow() {
+    let unimock = Unimock::new(SingleArgMock::method1.stub(|each| {
+        each.call(matching!((s) if s.is_empty()))
+            .returns(String::new());
+        each.call(matching!("a")).returns("A");
+    }));
+
+    assert_eq!("A", unimock.method1("a"));
+}
+
+#[unimock(api=AuthMock)]
+trait Auth {
+    fn login(&self, user: &str, #[unimock(redact)] password: &str) -> bool;
+}
+
+#[test]
+#[should_panic(expected = "Auth::login(\"user\", <redacted>): No mock implementation found.")]
+fn should_redact_arguments_in_call_debug() {
+    Unimock::new(()).login("user", "secret");
+}
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+#[test]
+#[should_panic(
+    expected = "Auth::login(\"user\", <redacted>): No matching call patterns. \nEquality mismatch for input #1:\nDiagnostics are not available because the input is redacted."
+)]
+fn should_redact_arguments_in_mismatches() {
+    Unimock::new(
+        AuthMock::login
+            .some_call(matching!("user", eq!(&"hunter2")))
+            .returns(true),
+    )
+    .login("user", "secret");
+}
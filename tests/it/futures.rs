@@ -0,0 +1,124 @@
+use core::task::Poll;
+
+use futures::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+    StreamExt,
+};
+use unimock::{
+    mock::core::Typed,
+    mock::futures::{
+        io::{AsyncBufReadMock, AsyncReadMock, AsyncSeekMock, AsyncWriteMock},
+        sink::SinkMock,
+        stream::StreamMock,
+    },
+    *,
+};
+
+#[tokio::test]
+async fn test_async_read() {
+    let mut unimock = Unimock::new(AsyncReadMock::poll_read.next_call(matching!(_)).mutates(
+        |buf, _| {
+            buf[..2].copy_from_slice(b"ok");
+            Poll::Ready(Ok(2))
+        },
+    ));
+
+    let mut buf = [0; 4];
+    assert_eq!(2, unimock.read(&mut buf).await.unwrap());
+    assert_eq!(b"ok", &buf[..2]);
+}
+
+#[tokio::test]
+async fn test_async_buf_read() {
+    let mut unimock = Unimock::new((
+        AsyncBufReadMock::poll_fill_buf
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(b"line\nrest".to_vec()))),
+        AsyncBufReadMock::consume
+            .next_call(matching!(5))
+            .returns(()),
+    ));
+
+    let mut line = String::new();
+    assert_eq!(5, unimock.read_line(&mut line).await.unwrap());
+    assert_eq!("line\n", line);
+}
+
+#[tokio::test]
+async fn test_async_write() {
+    let mut unimock = Unimock::new((
+        AsyncWriteMock::poll_write
+            .next_call(matching!(_, eq!(b"hello")))
+            .returns(Poll::Ready(Ok(5))),
+        AsyncWriteMock::poll_flush
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(()))),
+        AsyncWriteMock::poll_close
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(()))),
+    ));
+
+    unimock.write_all(b"hello").await.unwrap();
+    unimock.flush().await.unwrap();
+    unimock.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_seek() {
+    let mut unimock = Unimock::new(
+        AsyncSeekMock::poll_seek
+            .next_call(matching!(_, std::io::SeekFrom::End(-1)))
+            .returns(Poll::Ready(Ok(41))),
+    );
+
+    assert_eq!(41, unimock.seek(std::io::SeekFrom::End(-1)).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_stream() {
+    let stream = Typed::<String>::new(Unimock::new((
+        StreamMock::poll_next
+            .with_types::<String>()
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Some("a".to_string()))),
+        StreamMock::poll_next
+            .with_types::<String>()
+            .next_call(matching!(_))
+            .mutates(|cx, _| {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }),
+        StreamMock::poll_next
+            .with_types::<String>()
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Some("b".to_string()))),
+        StreamMock::poll_next
+            .with_types::<String>()
+            .next_call(matching!(_))
+            .returns(Poll::Ready(None)),
+    )));
+
+    assert_eq!(vec!["a", "b"], StreamExt::collect::<Vec<_>>(stream).await);
+}
+
+#[tokio::test]
+async fn test_sink() {
+    use futures::SinkExt;
+
+    let mut unimock = Unimock::new((
+        SinkMock::poll_ready
+            .with_types::<i32>()
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(()))),
+        SinkMock::start_send
+            .with_types::<i32>()
+            .next_call(matching!(42))
+            .returns(Ok(())),
+        SinkMock::poll_flush
+            .with_types::<i32>()
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(()))),
+    ));
+
+    unimock.send(42).await.unwrap();
+}
@@ -0,0 +1,68 @@
+use unimock::*;
+
+#[unimock(api = LendingMock, type Item<'a> = &'a str;)]
+trait Lending {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn get<'a>(&'a self) -> Self::Item<'a>;
+    fn get_elided(&self) -> Self::Item<'_>;
+}
+
+#[test]
+fn borrowed_gat() {
+    let u = Unimock::new((
+        LendingMock::get.next_call(matching!()).returns("a"),
+        LendingMock::get_elided.next_call(matching!()).returns("b"),
+    ));
+    assert_eq!("a", u.get());
+    assert_eq!("b", u.get_elided());
+}
+
+#[unimock(api = LendingIteratorMock, type Item<'a> = &'a [u8];)]
+trait LendingIterator {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next<'a>(&'a mut self) -> Option<Self::Item<'a>>;
+}
+
+#[test]
+fn lending_iterator() {
+    let mut u = Unimock::new((
+        LendingIteratorMock::next
+            .next_call(matching!())
+            .returns(Some([1, 2].as_slice())),
+        LendingIteratorMock::next
+            .next_call(matching!())
+            .returns(None::<&[u8]>),
+    ));
+    assert_eq!(Some([1, 2].as_slice()), u.next());
+    assert_eq!(None, u.next());
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Guard<'a, T>(&'a T);
+
+#[unimock(api = LockMock, type Guard<'a, T: 'a> = Guard<'a, T>;)]
+trait Lock {
+    type Guard<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn lock<'a>(&'a self) -> Self::Guard<'a, i32>;
+    fn try_lock<'a>(&'a self) -> Option<Self::Guard<'a, i32>>;
+}
+
+#[test]
+fn gat_in_option() {
+    let u = Unimock::new((
+        LockMock::lock.next_call(matching!()).returns(Guard(&1)),
+        LockMock::try_lock.next_call(matching!()).returns(None),
+    ));
+    assert_eq!(Guard(&1), u.lock());
+    assert_eq!(None, u.try_lock());
+}
@@ -98,11 +98,13 @@ mod param {
 mod combined {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock]
     trait GenericBounds<I: Debug, O: Clone> {
         fn generic_bounds(&self, param: I) -> O;
     }
 
+    #[allow(dead_code)]
     #[unimock]
     trait GenericWhereBounds<I, O>
     where
@@ -117,6 +119,7 @@ mod combined {
 mod async_generic {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock]
     #[async_trait::async_trait]
     trait AsyncTraitGenericBounds<I: Debug, O: Clone> {
@@ -127,6 +130,7 @@ mod async_generic {
 mod generic_without_module {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock(api=[Func])]
     trait WithModule<T: Debug> {
         fn func(&self) -> T;
@@ -143,11 +147,13 @@ mod generic_without_module {
 mod generic_with_unmock {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock(unmock_with=[gen_default(self)])]
     trait UnmockMe<T: Default> {
         fn unmock_me(&self) -> T;
     }
 
+    #[allow(dead_code)]
     #[unimock(unmock_with=[gen_default(self)])]
     trait UnmockMeWhere<T>
     where
@@ -266,6 +272,7 @@ mod generic_combo {
 
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock(api=MockCombo)]
     trait ComboRet<T: 'static> {
         fn ret<U>(&self, u: U, a: impl Any + 'static) -> (T, &U)
@@ -273,6 +280,7 @@ mod generic_combo {
             U: 'static;
     }
 
+    #[allow(dead_code)]
     #[cfg(feature = "std")]
     #[unimock(api=MockAsyncCombo)]
     #[async_trait::async_trait]
@@ -286,6 +294,7 @@ mod self_type {
 
     pub struct Generic<T>(T);
 
+    #[allow(dead_code)]
     #[unimock(api=SelfParamMock)]
     trait SelfParam {
         fn self_param(&self, p: Generic<Self>)
@@ -293,6 +302,7 @@ mod self_type {
             Self: Sized;
     }
 
+    #[allow(dead_code)]
     #[unimock(api=SelfReturnMock)]
     trait SelfReturn {
         fn self_return(&self) -> Generic<Self>
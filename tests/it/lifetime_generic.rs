@@ -0,0 +1,89 @@
+use unimock::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a>(&'a str);
+
+#[unimock(api = ParserMock)]
+trait Parser<'a> {
+    fn parse(&self, s: &'a str) -> Token<'a>;
+}
+
+#[test]
+fn lifetime_param() {
+    let u = Unimock::new(
+        ParserMock::parse
+            .next_call(matching!("input"))
+            .returns(Token("token")),
+    );
+    assert_eq!(Token("token"), u.parse("input"));
+}
+
+#[unimock(api = MultiMock)]
+trait Multi<'a, 'b: 'a> {
+    fn slice(&self, s: &'a str) -> &'a str;
+    fn pair(&self, a: &'a str, b: &'b str) -> (Token<'a>, Token<'b>);
+    fn opt(&self) -> Option<&'b str>;
+}
+
+#[test]
+fn bounded_lifetime_params() {
+    let u = Unimock::new((
+        MultiMock::slice.next_call(matching!("in")).returns("out"),
+        MultiMock::pair
+            .next_call(matching!("a", "b"))
+            .returns((Token("x"), Token("y"))),
+        MultiMock::opt.next_call(matching!()).returns(Some("o")),
+    ));
+    assert_eq!("out", u.slice("in"));
+    assert_eq!((Token("x"), Token("y")), u.pair("a", "b"));
+    assert_eq!(Some("o"), Multi::opt(&u));
+}
+
+#[unimock(api = MixedMock)]
+trait Mixed<'a, T: 'a> {
+    fn get(&self, s: &'a str) -> T;
+    fn borrow(&self, t: &'a T) -> bool;
+    fn default_body(&self, s: &'a str) -> usize {
+        s.len()
+    }
+}
+
+#[test]
+fn lifetime_and_type_params() {
+    let u = Unimock::new((
+        MixedMock::get
+            .with_types::<i32>()
+            .next_call(matching!("s"))
+            .returns(4),
+        MixedMock::borrow
+            .with_types::<i32>()
+            .next_call(matching!(_))
+            .returns(true),
+        MixedMock::default_body
+            .with_types::<i32>()
+            .next_call(matching!("ab"))
+            .default_implementation(),
+    ));
+    let t: i32 = u.get("s");
+    assert!(u.borrow(&t));
+    assert_eq!(2, Mixed::<i32>::default_body(&u, "ab"));
+}
+
+#[unimock(api = WhereMock)]
+trait WhereLifetime<'a, T>
+where
+    T: 'a,
+{
+    fn get_where(&self, s: &'a str) -> T;
+}
+
+#[test]
+fn lifetime_in_where_clause() {
+    let u = Unimock::new(
+        WhereMock::get_where
+            .with_types::<u32>()
+            .next_call(matching!("s"))
+            .returns(7_u32),
+    );
+    assert_eq!(7, WhereLifetime::<u32>::get_where(&u, "s"));
+}
@@ -1,16 +1,46 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "std")]
+// `async fn` and `-> impl Trait` in traits require Rust 1.75
+#[rustversion::since(1.75)]
+mod async_fn_in_trait {
+    include!("async_fn_in_trait.rs");
+}
+
 #[cfg(any(feature = "std", feature = "spin-lock"))]
 mod basic;
 
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod borrowed_mut;
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod const_generic;
+
 mod default_impl;
 mod errors;
+
+#[cfg(feature = "mock-futures")]
+mod futures;
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod gat;
+
 mod generic;
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod lifetime_generic;
+
 mod matching_eq;
 
+#[cfg(feature = "std")]
+mod matching_snapshot;
+
 #[cfg(any(feature = "std", feature = "spin-lock"))]
 mod matching_pat;
 
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod mirror_wrapper;
+
 #[cfg(any(feature = "std", feature = "spin-lock"))]
 mod mixed;
 
@@ -19,12 +49,38 @@ mod mock_order;
 
 mod prefix;
 
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod returns_mock;
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+// `async fn` and `-> impl Trait` in traits require Rust 1.75
+#[rustversion::since(1.75)]
+mod rpit {
+    include!("rpit.rs");
+}
+
+#[cfg(feature = "std")]
+// `async fn` and `-> impl Trait` in traits require Rust 1.75
+#[rustversion::since(1.75)]
+mod rpit_future {
+    include!("rpit_future.rs");
+}
+
 #[cfg(all(feature = "pretty-print", any(feature = "std", feature = "spin-lock")))]
 mod pretty_mismatches;
 
+#[cfg(feature = "mock-serde")]
+mod serde;
+
 #[cfg(all(feature = "mock-core", feature = "mock-std"))]
 mod std;
 
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod supertraits;
+
+#[cfg(feature = "mock-tokio")]
+mod tokio;
+
 mod unmock;
 
 fn main() {}
@@ -1,4 +1,4 @@
-use unimock::private::lib::{String, ToString};
+use unimock::private::lib::{vec, String, ToString, Vec};
 use unimock::*;
 
 /// Regression test for compile error:
@@ -42,3 +42,37 @@ fn matching_str_or() {
     );
     u.f("a".to_string());
 }
+
+#[unimock(api = FillMock)]
+trait Fill {
+    fn fill(&self, label: &str, buf: &mut Vec<i32>);
+}
+
+#[test]
+fn matching_mutation() {
+    let u = Unimock::new(FillMock::fill.stub(|each| {
+        each.call(matching!(("a", mutation!(buf)) if buf.is_empty()))
+            .mutates(|buf, _| buf.push(1));
+        each.call(matching!("a", mutation!([1, ..])))
+            .mutates(|buf, _| buf.push(2));
+    }));
+
+    let mut buf = vec![];
+    u.fill("a", &mut buf);
+    u.fill("a", &mut buf);
+    assert_eq!(vec![1, 2], buf);
+}
+
+#[test]
+#[should_panic(
+    expected = "Fill::fill(\"a\", PhantomMut): No matching call patterns. \nPattern mismatch for input #1"
+)]
+fn matching_mutation_mismatch() {
+    let u = Unimock::new(
+        FillMock::fill
+            .some_call(matching!("a", mutation!([])))
+            .mutates(|_, _| {}),
+    );
+
+    u.fill("a", &mut vec![42]);
+}
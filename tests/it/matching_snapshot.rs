@@ -0,0 +1,99 @@
+use std::path::Path;
+
+use unimock::private::lib::{String, ToString};
+use unimock::*;
+
+// The fields are only read through the Debug representation stored in snapshots.
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct Message {
+    pub title: String,
+    pub lines: Vec<&'static str>,
+}
+
+#[unimock(api = SenderMock)]
+trait Sender {
+    fn send(&self, message: &Message) -> bool;
+    fn send_to(&self, message: &Message, recipient: u32) -> bool;
+}
+
+fn message(title: &str) -> Message {
+    Message {
+        title: title.to_string(),
+        lines: vec!["first", "second"],
+    }
+}
+
+/// Run the test with snapshots stored in a temporary directory, instead of `tests/snapshots`.
+fn with_temp_snapshots(test: impl FnOnce(&Path)) {
+    let root = tempfile::tempdir().unwrap();
+    unimock::private::with_snapshot_root(root.path(), || test(root.path()));
+}
+
+#[test]
+fn matching_snapshot() {
+    let u = Unimock::new(
+        SenderMock::send
+            .next_call(matching!(snapshot!("message")))
+            .returns(true),
+    );
+
+    assert!(u.send(&message("hello")));
+}
+
+#[test]
+#[should_panic(expected = "Snapshot mismatch for input #0 (actual / expected):")]
+fn snapshot_mismatch() {
+    let u = Unimock::new(
+        SenderMock::send
+            .next_call(matching!(snapshot!("message_mismatch")))
+            .returns(true),
+    );
+
+    u.send(&message("goodbye"));
+}
+
+#[test]
+fn snapshot_gets_created_when_missing() {
+    with_temp_snapshots(|root| {
+        let u = Unimock::new(
+            SenderMock::send
+                .each_call(matching!(snapshot!("created_when_missing")))
+                .returns(true),
+        );
+
+        assert!(u.send(&message("new")));
+        assert!(u.send(&message("new")));
+
+        let created = std::fs::read_to_string(root.join("created_when_missing.snap")).unwrap();
+        assert!(created.contains("title: \"new\""));
+    });
+}
+
+#[test]
+fn snapshot_is_not_written_by_unselected_pattern() {
+    with_temp_snapshots(|root| {
+        let u = Unimock::new(SenderMock::send_to.stub(|each| {
+            each.call(matching!(snapshot!("not_selected"), eq!(&2)))
+                .returns(false);
+            each.call(matching!(_, _)).returns(true);
+        }));
+
+        assert!(u.send_to(&message("hello"), 1));
+        assert!(!root.join("not_selected.snap").exists());
+    });
+}
+
+#[test]
+fn snapshot_is_not_written_by_failed_arm() {
+    with_temp_snapshots(|root| {
+        let u = Unimock::new(
+            SenderMock::send_to
+                .next_call(matching!((snapshot!("failed_arm"), eq!(&2)) | (_, 1)))
+                .returns(true),
+        );
+
+        assert!(u.send_to(&message("hello"), 1));
+        assert!(!root.join("failed_arm.snap").exists());
+    });
+}
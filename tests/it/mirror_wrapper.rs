@@ -0,0 +1,44 @@
+//! Mocking traits defined in other crates, which can't be implemented for `Unimock` directly.
+
+use core::fmt::Write;
+use unimock::*;
+
+#[derive(Clone)]
+pub struct MockWrapper(Unimock);
+
+#[unimock(api = FmtWriteMock, mirror = core::fmt::Write, wrapper = MockWrapper)]
+trait FmtWrite {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result;
+}
+
+#[unimock(api = IteratorMock, mirror = Iterator, wrapper = MockWrapper, type Item = i32;)]
+trait Iter {
+    type Item;
+
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+#[test]
+fn default_methods_use_mocked_methods() {
+    let mut w = MockWrapper(Unimock::new(
+        FmtWriteMock::write_str
+            .each_call(matching!(_))
+            .returns(Ok(())),
+    ));
+
+    // `write_fmt` and `write_char` are provided by `core::fmt::Write`:
+    let n = 1;
+    write!(w, "{n}c").unwrap();
+    w.write_char('x').unwrap();
+}
+
+#[test]
+fn foreign_trait_with_associated_type() {
+    let w = MockWrapper(Unimock::new((
+        IteratorMock::next.next_call(matching!()).returns(Some(1)),
+        IteratorMock::next.next_call(matching!()).returns(Some(2)),
+        IteratorMock::next.next_call(matching!()).returns(None),
+    )));
+
+    assert_eq!(3, w.sum::<i32>());
+}
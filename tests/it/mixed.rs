@@ -1,6 +1,6 @@
 use core::task::Poll;
 
-use unimock::private::lib::{vec, ToString, Vec};
+use unimock::private::lib::{vec, BTreeMap, Cow, ToString, Vec};
 use unimock::*;
 
 mod clone {
@@ -58,6 +58,7 @@ trait InResult {
 
 // Note: This should only be mockable with static lifetimes
 // It should not pick a Mixed output mediator.
+#[allow(dead_code)]
 #[unimock]
 trait InResultWithComplexLifetimes {
     fn foo<'s, 'i>(&'s self, a: &'i str) -> Result<&'s str, &'i str>;
@@ -108,7 +109,7 @@ fn in_result_clone_acrobatics() {
 
 #[test]
 #[should_panic(
-    expected = "InResult::ok_no_clone: Expected InResult::ok_no_clone(_) at tests/it/mixed.rs:116 to match exactly 1 call, but it actually matched 2 calls."
+    expected = "InResult::ok_no_clone: Expected InResult::ok_no_clone(_) at tests/it/mixed.rs:117 to match exactly 1 call, but it actually matched 2 calls."
 )]
 fn in_result_may_multi_respond_on_ok_no_clone() {
     let u = Unimock::new(
@@ -137,12 +138,14 @@ fn in_vec() {
     assert_eq!(vec![&1, &2, &3], u.vector());
 }
 
+#[allow(dead_code)]
 #[unimock(api = MixedTupleMock)]
 trait MixedTuple {
     fn tuple1(&self) -> (&i32,);
     fn tuple2a(&self) -> (&i32, i32);
     fn tuple2b(&self) -> (i32, &i32);
     fn tuple4(&self) -> (&clone::Nope, clone::Nope, &clone::Sure, clone::Sure);
+    fn tuple_borrowed_nope(&self) -> (&clone::Nope, clone::Sure);
 }
 
 #[test]
@@ -211,3 +214,161 @@ fn in_poll() {
     assert_eq!(Poll::Ready(Ok(&42)), <Unimock as InPoll>::poll_result(&u));
     assert_eq!(Poll::Pending, <Unimock as InPoll>::poll_option(&u));
 }
+
+#[test]
+fn in_vec_answers() {
+    let u = Unimock::new(
+        InVecMock::vector
+            .each_call(matching!())
+            .answers(|_| vec![1, 2]),
+    );
+
+    assert_eq!(vec![&1, &2], u.vector());
+    assert_eq!(vec![&1, &2], u.vector());
+}
+
+#[test]
+fn mixed_tuple_each_call_without_borrowed_clone() {
+    let u = Unimock::new(
+        MixedTupleMock::tuple_borrowed_nope
+            .each_call(matching!())
+            .returns((clone::Nope, clone::Sure)),
+    );
+
+    for _ in 0..3 {
+        assert_eq!((&clone::Nope, clone::Sure), u.tuple_borrowed_nope());
+    }
+}
+
+mod alias {
+    pub type Result<T> = core::result::Result<T, super::clone::Sure>;
+}
+
+// Not a `Result` alias, despite the name.
+type SearchResult<T> = Lookup<T>;
+
+#[unimock(api = NestedMock)]
+trait Nested {
+    fn option_pair(&self) -> (Option<&str>, &i32);
+    fn map(&self) -> BTreeMap<&str, Option<&str>>;
+    fn cow(&self) -> Cow<'_, str>;
+    fn cows(&self) -> Vec<Cow<'_, str>>;
+    fn result_alias(&self) -> alias::Result<&str>;
+}
+
+#[test]
+fn nested_tuple_of_option() {
+    let u = Unimock::new((
+        NestedMock::option_pair
+            .next_call(matching!())
+            .returns((Some("a".to_string()), 1)),
+        NestedMock::option_pair
+            .next_call(matching!())
+            .returns((None::<&str>, 2)),
+    ));
+
+    assert_eq!((Some("a"), &1), u.option_pair());
+    assert_eq!((None, &2), u.option_pair());
+}
+
+#[test]
+fn nested_map() {
+    let u = Unimock::new(
+        NestedMock::map
+            .each_call(matching!())
+            .returns(BTreeMap::from([("a", Some("x".to_string())), ("b", None)])),
+    );
+
+    for _ in 0..2 {
+        assert_eq!(BTreeMap::from([("a", Some("x")), ("b", None)]), u.map());
+    }
+}
+
+#[test]
+fn cow_and_result_alias() {
+    let u = Unimock::new((
+        NestedMock::cow.each_call(matching!()).returns("borrowed"),
+        NestedMock::cows
+            .next_call(matching!())
+            .answers(|_| vec!["owned".to_string()]),
+        NestedMock::result_alias
+            .each_call(matching!())
+            .returns(Err::<&str, _>(clone::Sure)),
+    ));
+
+    assert!(matches!(u.cow(), Cow::Borrowed("borrowed")));
+    assert!(matches!(u.cow(), Cow::Borrowed("borrowed")));
+    assert_eq!(vec![Cow::<str>::Owned("owned".to_string())], u.cows());
+    assert_eq!(Err(clone::Sure), u.result_alias());
+    assert_eq!(Err(clone::Sure), u.result_alias());
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Lookup<T> {
+    Found(T),
+    Missing,
+}
+
+impl<D: output::MixedPart> output::MixedContainer for Lookup<D> {
+    type Response = Lookup<<D as output::Respond>::Type>;
+    type Output<'u> = Lookup<<D as output::Output<'u, D>>::Type>;
+
+    fn from_response<'u>(
+        response: Self::Response,
+        value_chain: &'u value_chain::ValueChain,
+    ) -> Self::Output<'u> {
+        match response {
+            Lookup::Found(value) => Lookup::Found(D::from_response(value, value_chain)),
+            Lookup::Missing => Lookup::Missing,
+        }
+    }
+
+    fn try_from_borrowed_response<'u>(
+        response: &'u Self::Response,
+    ) -> Result<Self::Output<'u>, output::ResponderError> {
+        Ok(match response {
+            Lookup::Found(value) => Lookup::Found(D::try_from_borrowed_response(value)?),
+            Lookup::Missing => Lookup::Missing,
+        })
+    }
+}
+
+impl<A, D: output::FromMixedValue<A>> output::MixedFrom<Lookup<A>> for Lookup<D> {
+    fn from_value(value: Lookup<A>) -> Lookup<<D as output::Respond>::Type> {
+        match value {
+            Lookup::Found(value) => Lookup::Found(D::from_mixed_value(value)),
+            Lookup::Missing => Lookup::Missing,
+        }
+    }
+}
+
+#[unimock(api = UserContainerMock)]
+trait UserContainer {
+    fn lookup(&self, key: &str) -> Lookup<&clone::Nope>;
+    fn lookup_result(&self) -> Result<Lookup<&str>, clone::Sure>;
+    fn search(&self) -> SearchResult<&str>;
+}
+
+#[test]
+fn user_defined_container() {
+    let u = Unimock::new((
+        UserContainerMock::lookup
+            .each_call(matching!("found"))
+            .returns(Lookup::Found(clone::Nope)),
+        UserContainerMock::lookup
+            .each_call(matching!("missing"))
+            .returns(Lookup::<&clone::Nope>::Missing),
+        UserContainerMock::lookup_result
+            .next_call(matching!())
+            .returns(Ok(Lookup::Found("x"))),
+        UserContainerMock::search
+            .next_call(matching!())
+            .returns(Lookup::Found("y")),
+    ));
+
+    assert_eq!(Lookup::Found(&clone::Nope), u.lookup("found"));
+    assert_eq!(Lookup::Found(&clone::Nope), u.lookup("found"));
+    assert_eq!(Lookup::Missing, u.lookup("missing"));
+    assert_eq!(Ok(Lookup::Found("x")), u.lookup_result());
+    assert_eq!(Lookup::Found("y"), u.search());
+}
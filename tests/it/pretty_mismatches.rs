@@ -149,3 +149,29 @@ fn should_print_all_mismatches_on_matched_function() {
 
     u.foo(S("c"));
 }
+
+#[test]
+#[should_panic(
+    expected = "Trait::foo(S(\"c\"), 42): No matching call patterns. \nEquality mismatch for input #0:\nActual value did not equal expected value (expected value has no Debug representation). Actual value:\nS(\"c\")"
+)]
+fn should_print_mismatches_using_custom_debug_formatter() {
+    #[derive(Eq, PartialEq)]
+    pub struct S(&'static str);
+
+    fn fmt_s(s: &S, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "S({:?})", s.0)
+    }
+
+    #[unimock(api=TraitMock)]
+    trait Trait {
+        fn foo(&self, #[unimock(debug_with = fmt_s)] s: &S, n: i32);
+    }
+
+    let u = Unimock::new(
+        TraitMock::foo
+            .some_call(matching!(eq!(&&S("b")), _))
+            .returns(()),
+    );
+
+    u.foo(&S("c"), 42);
+}
@@ -0,0 +1,162 @@
+use unimock::private::lib::{Arc, Box};
+use unimock::*;
+
+#[unimock(api = ConnectionMock, dyn_mock = true)]
+pub trait Connection {
+    fn query(&self, sql: &str) -> i32;
+}
+
+#[unimock(api = RepoMock, dyn_mock = true)]
+pub trait Repo<T>: Send + Sync {
+    fn get(&self, key: u32) -> T;
+}
+
+#[unimock(api = PoolMock)]
+trait Pool {
+    fn connection(&self) -> Box<dyn Connection>;
+    fn shared(&self) -> Arc<dyn Connection + Send + Sync>;
+    fn repo(&self) -> Box<dyn Repo<bool>>;
+}
+
+#[test]
+fn returns_boxed_child_mock() {
+    let pool = Unimock::new(
+        PoolMock::connection
+            .next_call(matching!())
+            .returns_mock(ConnectionMock::query.next_call(matching!("q")).returns(7)),
+    );
+
+    let connection = pool.connection();
+    assert_eq!(7, connection.query("q"));
+}
+
+#[test]
+fn returns_arc_child_mock_each_call() {
+    let pool = Unimock::new((
+        PoolMock::shared
+            .each_call(matching!())
+            .returns_mock(ConnectionMock::query.each_call(matching!(_)).returns(1)),
+        PoolMock::repo.each_call(matching!()).returns_mock(
+            RepoMock::get
+                .with_types::<bool>()
+                .each_call(matching!(42))
+                .returns(true),
+        ),
+    ));
+
+    let a = pool.shared();
+    let b = pool.shared();
+    assert_eq!(2, a.query("a") + b.query("b"));
+    assert!(pool.repo().get(42));
+}
+
+#[test]
+fn call_order_is_tracked_across_parent_and_child() {
+    let pool = Unimock::new((
+        PoolMock::connection.next_call(matching!()).returns_mock(
+            ConnectionMock::query
+                .next_call(matching!("first"))
+                .returns(1),
+        ),
+        PoolMock::connection.next_call(matching!()).returns_mock(
+            ConnectionMock::query
+                .next_call(matching!("second"))
+                .returns(2),
+        ),
+    ));
+
+    let first = pool.connection();
+    assert_eq!(1, first.query("first"));
+    let second = pool.connection();
+    assert_eq!(2, second.query("second"));
+}
+
+#[test]
+#[should_panic(
+    expected = "Pool::connection(): Method matched in wrong order. Expected a call matching Connection::query(\"first\")"
+)]
+fn parent_call_before_child_call_panics() {
+    let pool = Unimock::new((
+        PoolMock::connection.next_call(matching!()).returns_mock(
+            ConnectionMock::query
+                .next_call(matching!("first"))
+                .returns(1),
+        ),
+        PoolMock::connection.next_call(matching!()).returns_mock(
+            ConnectionMock::query
+                .next_call(matching!("second"))
+                .returns(2),
+        ),
+    ));
+
+    let _first = pool.connection();
+    pool.connection();
+}
+
+#[test]
+#[should_panic(expected = "Mock for Connection::query was never called.")]
+fn child_is_verified_by_the_parent() {
+    let pool = Unimock::new(
+        PoolMock::connection
+            .next_call(matching!())
+            .returns_mock(ConnectionMock::query.next_call(matching!("q")).returns(7)),
+    );
+
+    drop(pool.connection());
+}
+
+#[test]
+fn children_have_separate_call_patterns() {
+    let pool = Unimock::new(PoolMock::connection.stub(|each| {
+        each.call(matching!())
+            .returns_mock(ConnectionMock::query.each_call(matching!(_)).returns(1))
+            .once()
+            .then()
+            .returns_mock(ConnectionMock::query.each_call(matching!(_)).returns(2));
+    }));
+
+    let first = pool.connection();
+    let second = pool.connection();
+    assert_eq!(2, second.query("q"));
+    assert_eq!(1, first.query("q"));
+}
+
+#[test]
+#[should_panic(expected = "Connection::query(\"q\"): No mock implementation found.")]
+fn parent_does_not_match_child_call_patterns() {
+    let pool = Unimock::new(
+        PoolMock::connection
+            .next_call(matching!())
+            .returns_mock(ConnectionMock::query.each_call(matching!(_)).returns(1)),
+    );
+
+    let _connection = pool.connection();
+    pool.query("q");
+}
+
+#[test]
+fn parent_and_children_stub_the_same_method() {
+    let pool = Unimock::new((
+        ConnectionMock::query.each_call(matching!(_)).returns(0),
+        PoolMock::connection
+            .each_call(matching!())
+            .returns_mock(ConnectionMock::query.each_call(matching!(_)).returns(1)),
+        PoolMock::shared
+            .each_call(matching!())
+            .returns_mock(ConnectionMock::query.each_call(matching!(_)).returns(2)),
+    ));
+
+    assert_eq!(0, pool.query("q"));
+    assert_eq!(1, pool.connection().query("q"));
+    assert_eq!(2, pool.shared().query("q"));
+}
+
+#[test]
+fn child_falls_back_to_parent_call_patterns() {
+    let pool = Unimock::new((
+        ConnectionMock::query.each_call(matching!(_)).returns(0),
+        PoolMock::connection.each_call(matching!()).returns_mock(()),
+    ));
+
+    assert_eq!(0, pool.connection().query("q"));
+}
@@ -0,0 +1,110 @@
+use core::fmt::Display;
+use unimock::private::lib::{vec, String, ToString, Vec};
+use unimock::*;
+
+#[unimock(api = ItemsMock)]
+trait Items {
+    fn items(&self) -> impl Iterator<Item = u32>;
+    fn label(&self, n: i32) -> impl Display + '_;
+    fn drain(&mut self) -> impl Iterator<Item = String>;
+}
+
+#[test]
+fn return_impl_trait() {
+    let mut u = Unimock::new((
+        ItemsMock::items
+            .next_call(matching!())
+            .returns(vec![1, 2, 3].into_iter()),
+        ItemsMock::label.next_call(matching!(42)).returns("answer"),
+        ItemsMock::drain
+            .next_call(matching!())
+            .returns(vec!["a".to_string()].into_iter()),
+    ));
+
+    assert_eq!(6, u.items().sum::<u32>());
+    assert_eq!("answer", u.label(42).to_string());
+    assert_eq!(vec!["a".to_string()], u.drain().collect::<Vec<_>>());
+}
+
+#[test]
+fn return_impl_trait_answers() {
+    let u = Unimock::new(ItemsMock::items.each_call(matching!()).answers(|_| 0..3));
+
+    assert_eq!(3, u.items().count());
+    assert_eq!(3, u.items().count());
+}
+
+mod unmocked {
+    use super::*;
+
+    #[unimock(api = CountMock, unmock_with=[count_to])]
+    trait Count {
+        fn count(&self, n: u32) -> impl Iterator<Item = u32>;
+    }
+
+    fn count_to(_: &impl core::any::Any, n: u32) -> impl Iterator<Item = u32> {
+        0..n
+    }
+
+    #[test]
+    fn unmock_impl_trait() {
+        let u = Unimock::new(CountMock::count.next_call(matching!(2)).unmocked());
+        assert_eq!(vec![0, 1], u.count(2).collect::<Vec<_>>());
+    }
+}
+
+mod default_body {
+    use super::*;
+
+    #[unimock(api = DefaultItemsMock)]
+    trait DefaultItems {
+        fn items(&self) -> impl Iterator<Item = u8> + 'static {
+            vec![1u8, 2].into_iter()
+        }
+    }
+
+    #[test]
+    fn delegates_to_default_body() {
+        let u = Unimock::new(());
+        assert_eq!(vec![1, 2], DefaultItems::items(&u).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn mocks_method_with_default_body() {
+        let u = Unimock::new(
+            DefaultItemsMock::items
+                .next_call(matching!())
+                .returns(vec![3u8].into_iter()),
+        );
+        assert_eq!(vec![3], DefaultItems::items(&u).collect::<Vec<_>>());
+    }
+}
+
+mod same_method_name {
+    use super::*;
+
+    #[unimock(api = NumbersMock)]
+    trait Numbers {
+        fn iter(&self) -> impl Iterator<Item = u32>;
+    }
+
+    #[unimock(api = WordsMock)]
+    trait Words {
+        fn iter(&self) -> impl Iterator<Item = String>;
+    }
+
+    #[test]
+    fn traits_in_the_same_module() {
+        let u = Unimock::new((
+            NumbersMock::iter
+                .next_call(matching!())
+                .returns(vec![1, 2].into_iter()),
+            WordsMock::iter
+                .next_call(matching!())
+                .returns(vec!["a".to_string()].into_iter()),
+        ));
+
+        assert_eq!(3, Numbers::iter(&u).sum::<u32>());
+        assert_eq!(vec!["a".to_string()], Words::iter(&u).collect::<Vec<_>>());
+    }
+}
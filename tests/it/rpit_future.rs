@@ -15,8 +15,12 @@ async fn rpit() {
     let u = Unimock::new((
         RpitFutureMock::m1.next_call(matching!()).returns(1337),
         RpitFutureMock::m2.next_call(matching!(42)).returns(1338),
+        RpitFutureMock::m3.next_call(matching!()).returns(1339),
+        RpitFutureMock::m4.next_call(matching!()).returns(1340),
     ));
 
     assert_eq!(u.m1().await, 1337);
     assert_eq!(u.m2(42).await, 1338);
+    assert_eq!(u.m3().await, 1339);
+    assert_eq!(u.m4().await, 1340);
 }
@@ -0,0 +1,326 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use unimock::{
+    mock::serde::{
+        de::{DeserializerMock, MapAccessMock, SeqAccessMock, VariantAccessMock, Visit},
+        ser::{
+            SerializeMapMock, SerializeSeqMock, SerializeStructMock, SerializeStructVariantMock,
+            SerializerMock,
+        },
+        Error,
+    },
+    *,
+};
+
+mod ser {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Serialize)]
+    struct Named {
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+    }
+
+    #[derive(Serialize)]
+    enum Shape {
+        Empty,
+        Circle(u8),
+        Rect { w: u8 },
+    }
+
+    #[test]
+    fn struct_fields_in_order() {
+        let u = Unimock::new((
+            SerializerMock::serialize_struct
+                .next_call(matching!("Point", 2))
+                .returns(Ok(())),
+            SerializeStructMock::serialize_field
+                .next_call(matching!("x"))
+                .returns(Ok(())),
+            SerializerMock::serialize_i32
+                .next_call(matching!(1))
+                .returns(Ok(())),
+            SerializeStructMock::serialize_field
+                .next_call(matching!("y"))
+                .returns(Ok(())),
+            SerializerMock::serialize_i32
+                .next_call(matching!(2))
+                .returns(Ok(())),
+            SerializeStructMock::end
+                .next_call(matching!())
+                .returns(Ok(())),
+        ));
+
+        Point { x: 1, y: 2 }.serialize(u).unwrap();
+    }
+
+    #[test]
+    fn field_error_stops_serialization() {
+        let u = Unimock::new((
+            SerializerMock::serialize_struct
+                .next_call(matching!("Point", 2))
+                .returns(Ok(())),
+            SerializeStructMock::serialize_field
+                .next_call(matching!("x"))
+                .returns(Err(Error::new("no x"))),
+        ));
+
+        assert_eq!(Err(Error::new("no x")), Point { x: 1, y: 2 }.serialize(u));
+    }
+
+    #[test]
+    fn option_and_skipped_field() {
+        let u = Unimock::new((
+            SerializerMock::serialize_struct
+                .next_call(matching!("Named", 1))
+                .returns(Ok(())),
+            SerializeStructMock::serialize_field
+                .next_call(matching!("name"))
+                .returns(Ok(())),
+            SerializerMock::serialize_some
+                .next_call(matching!())
+                .returns(Ok(())),
+            SerializerMock::serialize_str
+                .next_call(matching!("unimock"))
+                .returns(Ok(())),
+            SerializeStructMock::skip_field
+                .next_call(matching!("tags"))
+                .returns(Ok(())),
+            SerializeStructMock::end
+                .next_call(matching!())
+                .returns(Ok(())),
+        ));
+
+        Named {
+            name: Some("unimock".to_string()),
+            tags: vec![],
+        }
+        .serialize(u)
+        .unwrap();
+    }
+
+    #[test]
+    fn sequence() {
+        let u = Unimock::new((
+            SerializerMock::serialize_seq
+                .next_call(matching!(Some(2)))
+                .returns(Ok(())),
+            SerializeSeqMock::serialize_element
+                .next_call(matching!())
+                .returns(Ok(())),
+            SerializerMock::serialize_bool
+                .next_call(matching!(true))
+                .returns(Ok(())),
+            SerializeSeqMock::serialize_element
+                .next_call(matching!())
+                .returns(Ok(())),
+            SerializerMock::serialize_bool
+                .next_call(matching!(false))
+                .returns(Ok(())),
+            SerializeSeqMock::end.next_call(matching!()).returns(Ok(())),
+        ));
+
+        vec![true, false].serialize(u).unwrap();
+    }
+
+    #[test]
+    fn map() {
+        let u = Unimock::new((
+            SerializerMock::serialize_map
+                .next_call(matching!(Some(1)))
+                .returns(Ok(())),
+            SerializeMapMock::serialize_key
+                .next_call(matching!())
+                .returns(Ok(())),
+            SerializerMock::serialize_str
+                .next_call(matching!("k"))
+                .returns(Ok(())),
+            SerializeMapMock::serialize_value
+                .next_call(matching!())
+                .returns(Ok(())),
+            SerializerMock::serialize_u64
+                .next_call(matching!(7))
+                .returns(Ok(())),
+            SerializeMapMock::end.next_call(matching!()).returns(Ok(())),
+        ));
+
+        BTreeMap::from([("k", 7u64)]).serialize(u).unwrap();
+    }
+
+    #[test]
+    fn enum_variants() {
+        let u = Unimock::new((
+            SerializerMock::serialize_unit_variant
+                .next_call(matching!("Shape", 0, "Empty"))
+                .returns(Ok(())),
+            SerializerMock::serialize_newtype_variant
+                .next_call(matching!("Shape", 1, "Circle"))
+                .returns(Ok(())),
+            SerializerMock::serialize_u8
+                .next_call(matching!(3))
+                .returns(Ok(())),
+            SerializerMock::serialize_struct_variant
+                .next_call(matching!("Shape", 2, "Rect", 1))
+                .returns(Ok(())),
+            SerializeStructVariantMock::serialize_field
+                .next_call(matching!("w"))
+                .returns(Ok(())),
+            SerializerMock::serialize_u8
+                .next_call(matching!(4))
+                .returns(Ok(())),
+            SerializeStructVariantMock::end
+                .next_call(matching!())
+                .returns(Ok(())),
+        ));
+
+        Shape::Empty.serialize(u.clone()).unwrap();
+        Shape::Circle(3).serialize(u.clone()).unwrap();
+        Shape::Rect { w: 4 }.serialize(u).unwrap();
+    }
+}
+
+mod de {
+    use super::*;
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    enum Shape {
+        Empty,
+        Circle(u8),
+    }
+
+    #[test]
+    fn struct_from_map() {
+        let u = Unimock::new((
+            DeserializerMock::deserialize_struct
+                .next_call(matching!("Point", ["x", "y"]))
+                .returns(Ok(Visit::Map)),
+            MapAccessMock::next_key
+                .next_call(matching!())
+                .returns(Ok(true)),
+            DeserializerMock::deserialize_identifier
+                .next_call(matching!())
+                .returns(Ok(Visit::Str("y".to_string()))),
+            DeserializerMock::deserialize_i32
+                .next_call(matching!())
+                .returns(Ok(Visit::I64(2))),
+            MapAccessMock::next_key
+                .next_call(matching!())
+                .returns(Ok(true)),
+            DeserializerMock::deserialize_identifier
+                .next_call(matching!())
+                .returns(Ok(Visit::Str("x".to_string()))),
+            DeserializerMock::deserialize_i32
+                .next_call(matching!())
+                .returns(Ok(Visit::I64(1))),
+            MapAccessMock::next_key
+                .next_call(matching!())
+                .returns(Ok(false)),
+        ));
+
+        assert_eq!(Point { x: 1, y: 2 }, Point::deserialize(u).unwrap());
+    }
+
+    #[test]
+    fn missing_field() {
+        let u = Unimock::new((
+            DeserializerMock::deserialize_struct
+                .next_call(matching!("Point", _))
+                .returns(Ok(Visit::Map)),
+            MapAccessMock::next_key
+                .next_call(matching!())
+                .returns(Ok(false)),
+        ));
+
+        assert_eq!(Err(Error::new("missing field `x`")), Point::deserialize(u));
+    }
+
+    #[test]
+    fn invalid_type() {
+        let u = Unimock::new(
+            DeserializerMock::deserialize_u8
+                .next_call(matching!())
+                .returns(Ok(Visit::Str("nope".to_string()))),
+        );
+
+        assert_eq!(
+            Err(Error::new("invalid type: string \"nope\", expected u8")),
+            u8::deserialize(u)
+        );
+    }
+
+    #[test]
+    fn option_and_sequence() {
+        let u = Unimock::new((
+            DeserializerMock::deserialize_option
+                .next_call(matching!())
+                .returns(Ok(Visit::Some)),
+            DeserializerMock::deserialize_seq
+                .next_call(matching!())
+                .returns(Ok(Visit::Seq)),
+            SeqAccessMock::next_element
+                .next_call(matching!())
+                .returns(Ok(true)),
+            DeserializerMock::deserialize_bool
+                .next_call(matching!())
+                .returns(Ok(Visit::Bool(true))),
+            SeqAccessMock::next_element
+                .next_call(matching!())
+                .returns(Ok(false)),
+        ));
+
+        assert_eq!(
+            Some(vec![true]),
+            Option::<Vec<bool>>::deserialize(u).unwrap()
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        let u = Unimock::new((
+            DeserializerMock::deserialize_enum
+                .each_call(matching!("Shape", ["Empty", "Circle"]))
+                .returns(Ok(Visit::Enum)),
+            DeserializerMock::deserialize_identifier
+                .next_call(matching!())
+                .returns(Ok(Visit::Str("Empty".to_string()))),
+            DeserializerMock::deserialize_identifier
+                .next_call(matching!())
+                .returns(Ok(Visit::U64(1))),
+            VariantAccessMock::newtype_variant
+                .next_call(matching!())
+                .returns(Ok(())),
+            DeserializerMock::deserialize_u8
+                .next_call(matching!())
+                .returns(Ok(Visit::U64(5))),
+        ));
+
+        assert_eq!(Shape::Empty, Shape::deserialize(u.clone()).unwrap());
+        assert_eq!(Shape::Circle(5), Shape::deserialize(u).unwrap());
+    }
+
+    #[test]
+    fn human_readable() {
+        let u = Unimock::new(
+            DeserializerMock::is_human_readable
+                .next_call(matching!())
+                .returns(false),
+        );
+
+        assert!(!serde::Deserializer::is_human_readable(&u));
+        assert!(serde::Serializer::is_human_readable(&Unimock::new(())));
+    }
+}
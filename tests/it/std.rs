@@ -1,10 +1,12 @@
-#![allow(clippy::write_literal)]
+#![allow(clippy::write_literal, clippy::to_string_in_format_args)]
 
 use std::io::{BufRead, BufReader, Write};
 
 use unimock::{
     mock::{
         core::fmt::{DebugMock, DisplayMock},
+        core::iter::{DoubleEndedIteratorMock, ExactSizeIteratorMock, IteratorMock},
+        core::Typed,
         std::io::{ReadMock, WriteMock},
     },
     *,
@@ -45,6 +47,68 @@ fn test_debug() {
     assert_eq!("u", format!("{unimock:?}"));
 }
 
+#[test]
+fn test_iterator() {
+    let iter = Typed::<String>::new(Unimock::new((
+        IteratorMock::next
+            .with_types::<String>()
+            .next_call(matching!())
+            .returns(Some("a".to_string())),
+        IteratorMock::next
+            .with_types::<String>()
+            .next_call(matching!())
+            .returns(Some("b".to_string())),
+        IteratorMock::next
+            .with_types::<String>()
+            .next_call(matching!())
+            .returns(None),
+    )));
+
+    assert_eq!(vec!["a", "b"], iter.collect::<Vec<_>>());
+}
+
+#[test]
+fn test_iterator_size_hint() {
+    let iter = Typed::<i32>::new(Unimock::new(
+        IteratorMock::size_hint
+            .with_types::<i32>()
+            .next_call(matching!())
+            .returns((2, Some(2))),
+    ));
+    assert_eq!((2, Some(2)), iter.size_hint());
+
+    // Not mocked, so it uses the default implementation:
+    let iter = Typed::<i32>::new(Unimock::new(()));
+    assert_eq!((0, None), iter.size_hint());
+}
+
+#[test]
+fn test_double_ended_exact_size_iterator() {
+    let mut iter = Typed::<u8>::new(Unimock::new((
+        ExactSizeIteratorMock::len
+            .with_types::<u8>()
+            .next_call(matching!())
+            .returns(2_usize),
+        DoubleEndedIteratorMock::next_back
+            .with_types::<u8>()
+            .next_call(matching!())
+            .returns(Some(2)),
+        IteratorMock::next
+            .with_types::<u8>()
+            .next_call(matching!())
+            .returns(Some(1)),
+        DoubleEndedIteratorMock::next_back
+            .with_types::<u8>()
+            .next_call(matching!())
+            .returns(None),
+    )));
+
+    assert_eq!(2, iter.len());
+    assert_eq!(Some(2), iter.next_back());
+    assert_eq!(Some(1), iter.next());
+    assert_eq!(None, iter.next_back());
+}
+
 #[test]
 fn test_read() {
     let mut reader = BufReader::new(Unimock::new((
@@ -62,6 +126,21 @@ fn test_read() {
     assert_eq!("ok\n", line);
 }
 
+#[test]
+fn test_read_matching_buffer() {
+    use std::io::Read;
+
+    let mut reader = Unimock::new(
+        ReadMock::read
+            .next_call(matching!((mutation!(buf)) if buf.len() >= 2))
+            .mutates(|mut buf, _| buf.write(b"ok")),
+    );
+
+    let mut buf = [0; 2];
+    assert_eq!(2, reader.read(&mut buf).unwrap());
+    assert_eq!(b"ok", &buf);
+}
+
 #[test]
 fn test_write() {
     let mut unimock = Unimock::new((
@@ -90,13 +169,39 @@ fn test_write_fail() {
     write!(&mut unimock, "hello {}", "world".to_string()).unwrap();
 }
 
+#[test]
+fn test_write_fmt() {
+    let mut unimock = Unimock::new(
+        WriteMock::write_fmt
+            .next_call(matching!((args) if args.to_string() == "hello world"))
+            .returns(Ok(())),
+    );
+
+    use std::io::Write;
+    write!(&mut unimock, "hello {}", "world".to_string()).unwrap();
+}
+
+#[test]
+fn test_write_buffered() {
+    let unimock = Unimock::new(
+        WriteMock::write
+            .next_call(matching!(eq!(b"hello world")))
+            .returns(Ok("hello world".len())),
+    );
+
+    use std::io::Write;
+    let mut writer = std::io::BufWriter::new(unimock);
+    write!(&mut writer, "hello {}", "world").unwrap();
+    assert!(writer.into_inner().is_ok());
+}
+
 #[test]
 fn test_fmt_io_duplex_default_impl_implicit() {
     let unimock = Unimock::new((
         DisplayMock::fmt
             .next_call(matching!())
             .mutates(|f, _| write!(f, "hello {}", "unimock".to_string())),
-        // NOTE: write! calls `write_all` which should get re-routed to `write`:
+        // NOTE: write! calls `write_fmt`, which falls back to `write_all`, which should get re-routed to `write`:
         WriteMock::write
             .next_call(matching!(eq!(b"hello ")))
             .returns(Ok(6)),
@@ -113,18 +218,15 @@ fn test_fmt_io_duplex_default_impl_implicit() {
 #[test]
 fn test_fmt_io_duplex_default_impl_explicit() {
     let unimock = Unimock::new((
+        WriteMock::write_fmt
+            .next_call(matching!(_))
+            .default_implementation(),
         DisplayMock::fmt
             .next_call(matching!())
             .mutates(|f, _| write!(f, "hello {}", "unimock".to_string())),
-        WriteMock::write_all
-            .next_call(matching!(eq!(b"hello ")))
-            .default_implementation(),
         WriteMock::write
             .next_call(matching!(eq!(b"hello ")))
             .returns(Ok(6)),
-        WriteMock::write_all
-            .next_call(matching!(eq!(b"unimock")))
-            .default_implementation(),
         WriteMock::write
             .next_call(matching!(eq!(b"unimock")))
             .returns(Ok("uni".len())),
@@ -135,6 +237,699 @@ fn test_fmt_io_duplex_default_impl_explicit() {
     write!(&mut unimock.clone(), "{unimock}").unwrap();
 }
 
+mod ops {
+    use unimock::{
+        mock::core::ops::{
+            DerefMock, DerefMutMock, DropMock, FnMock, FnMutMock, FnOnceMock, IndexMock,
+            IndexMutMock,
+        },
+        mock::core::Typed,
+        *,
+    };
+
+    fn apply_twice(f: impl Fn(i32) -> i32, input: i32) -> i32 {
+        f(f(input))
+    }
+
+    #[test]
+    fn into_fn() {
+        let f = Unimock::new((
+            FnMock::call
+                .with_types::<i32, i32>()
+                .next_call(matching!(1))
+                .returns(2),
+            FnMock::call
+                .with_types::<i32, i32>()
+                .next_call(matching!(2))
+                .returns(4),
+        ))
+        .into_fn();
+
+        assert_eq!(4, apply_twice(f, 1));
+    }
+
+    #[test]
+    fn into_fn_with_several_arguments() {
+        let f = Unimock::new(
+            FnMock::call
+                .with_types::<(i32, &'static str), String>()
+                .next_call(matching!((1, "a")))
+                .returns("1a"),
+        )
+        .into_fn::<_, String>();
+
+        assert_eq!("1a", f((1, "a")));
+    }
+
+    #[test]
+    fn into_fn_mut() {
+        let mut f = Unimock::new(
+            FnMutMock::call_mut
+                .with_types::<(), u32>()
+                .each_call(matching!())
+                .returns(7u32)
+                .n_times(3),
+        )
+        .into_fn_mut::<(), u32>();
+
+        let sum: u32 = (0..3).map(|_| f(())).sum();
+        assert_eq!(21, sum);
+    }
+
+    fn call_with_four(f: impl FnOnce(String) -> usize) -> usize {
+        f("four".to_string())
+    }
+
+    #[test]
+    fn into_fn_once() {
+        let f = Unimock::new(
+            FnOnceMock::call_once
+                .with_types::<String, usize>()
+                .next_call(matching!((s) if s == "four"))
+                .returns(4usize),
+        )
+        .into_fn_once();
+
+        assert_eq!(4, call_with_four(f));
+    }
+
+    #[test]
+    fn index() {
+        let map = Typed::<str>::new(Unimock::new((
+            IndexMock::index
+                .with_types::<&'static str, str>()
+                .next_call(matching!("a"))
+                .returns("1".to_string()),
+            IndexMock::index
+                .with_types::<&'static str, str>()
+                .next_call(matching!("b"))
+                .returns("2".to_string()),
+        )));
+
+        assert_eq!("1", &map["a"]);
+        assert_eq!("2", &map["b"]);
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut vec = Typed::<i32>::new(Unimock::new(
+            IndexMutMock::index_mut
+                .with_types::<usize, i32>()
+                .next_call(matching!(0))
+                .returns(1),
+        ));
+
+        let element = &mut vec[0usize];
+        *element += 1;
+        assert_eq!(2, *element);
+    }
+
+    #[test]
+    fn deref() {
+        let string = Typed::<str>::new(Unimock::new(
+            DerefMock::deref
+                .with_types::<str>()
+                .each_call(matching!())
+                .returns("hello".to_string()),
+        ));
+
+        assert_eq!(5, string.len());
+        assert!(string.starts_with("he"));
+    }
+
+    #[test]
+    fn deref_mut() {
+        let mut vec = Typed::<Vec<i32>>::new(Unimock::new(
+            DerefMutMock::deref_mut
+                .with_types::<Vec<i32>>()
+                .next_call(matching!())
+                .returns(vec![1, 2]),
+        ));
+
+        let target = &mut *vec;
+        target.push(3);
+        assert_eq!(&[1, 2, 3], target.as_slice());
+    }
+
+    fn release<T>(_resource: T) {}
+
+    #[test]
+    fn observed_drop() {
+        let u = Unimock::new(DropMock::drop.next_call(matching!()).returns(()));
+        release(u.observe_drop());
+    }
+
+    #[test]
+    fn unobserved_drops() {
+        let u = Unimock::new(DropMock::drop.next_call(matching!()).returns(()));
+        let observed = u.observe_drop();
+
+        release(u.clone());
+        release((*observed).clone());
+        release(observed);
+    }
+
+    #[test]
+    fn unmocked_observed_drop() {
+        release(Unimock::new(()).observe_drop());
+    }
+}
+
+mod cmp {
+    use core::cmp::Ordering;
+    use std::collections::{BTreeSet, HashSet};
+
+    use unimock::{
+        mock::core::{
+            cmp::{OrdMock, PartialEqMock, PartialOrdMock},
+            hash::HashMock,
+            Keyed,
+        },
+        *,
+    };
+
+    #[test]
+    fn partial_eq_provided_ne() {
+        let a = Keyed::new(Unimock::new(
+            PartialEqMock::eq.next_call(matching!(_)).returns(true),
+        ));
+        let b = a.clone();
+
+        // `ne` is provided by `PartialEq`, and calls the mocked `eq`:
+        assert!(!a.ne(&b));
+    }
+
+    #[test]
+    #[should_panic = "PartialEq::eq(Keyed): No matching call patterns."]
+    fn partial_eq_mismatch_shows_other_operand() {
+        let a = Keyed::new(Unimock::new(
+            PartialEqMock::eq
+                .some_call(matching!((_) if false))
+                .returns(true),
+        ));
+        let b = a.clone();
+
+        let _ = a == b;
+    }
+
+    #[test]
+    fn partial_ord_count_comparisons() {
+        let a = Keyed::new(Unimock::new(
+            PartialOrdMock::partial_cmp
+                .each_call(matching!(_))
+                .returns(Some(Ordering::Greater))
+                .n_times(2),
+        ));
+        let b = a.clone();
+
+        assert!(a > b);
+        assert!(a >= b);
+    }
+
+    #[test]
+    fn ord_cmp() {
+        let a = Keyed::new(Unimock::new(
+            OrdMock::cmp.next_call(matching!(_)).returns(Ordering::Less),
+        ));
+        let b = a.clone();
+
+        assert_eq!(Ordering::Less, a.cmp(&b));
+    }
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn btree_set() {
+        let a = Keyed::new(Unimock::new(
+            OrdMock::cmp
+                .each_call(matching!(_))
+                .returns(Ordering::Equal),
+        ));
+
+        let mut set = BTreeSet::new();
+        assert!(set.insert(a.clone()));
+        assert!(!set.insert(a.clone()));
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn hash_set() {
+        let a = Keyed::new(Unimock::new((
+            HashMock::hash
+                .each_call(matching!())
+                .mutates(|state, _| state.write_u32(42)),
+            PartialEqMock::eq.each_call(matching!(_)).returns(true),
+        )));
+
+        let mut set = HashSet::new();
+        assert!(set.insert(a.clone()));
+        assert!(!set.insert(a.clone()));
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    #[should_panic = "PartialEq::eq(Keyed): No mock implementation found."]
+    fn default_has_no_mocks() {
+        let a = Keyed::default();
+        let b = a.clone();
+
+        let _ = a == b;
+    }
+
+    #[test]
+    fn into_inner() {
+        let a = Keyed::from(Unimock::new(()));
+        a.into_inner().verify();
+    }
+}
+
+mod error {
+    use std::error::Error;
+
+    use unimock::{mock::std::error::ErrorMock, *};
+
+    fn messages(error: &(dyn Error + 'static)) -> Vec<String> {
+        let mut messages = vec![error.to_string()];
+        let mut source = error.source();
+        while let Some(error) = source {
+            messages.push(error.to_string());
+            source = error.source();
+        }
+        messages
+    }
+
+    #[test]
+    fn source_chain() {
+        let error = Unimock::new(
+            ErrorMock::source
+                .returns_chain(["b", "c"])
+                .with_message("a"),
+        );
+
+        assert_eq!(vec!["a", "b", "c"], messages(&error));
+    }
+
+    #[test]
+    fn empty_source_chain() {
+        let error = Unimock::new(
+            ErrorMock::source
+                .returns_chain(core::iter::empty::<&str>())
+                .with_message("root"),
+        );
+
+        assert!(error.source().is_none());
+        assert_eq!("root", error.to_string());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Mock for Display::fmt was never called. Dead mocks should be removed."
+    )]
+    fn unused_message_fails_verification() {
+        let error = Unimock::new(ErrorMock::source.returns_chain(["b"]).with_message("a"));
+
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn boxed_error() {
+        fn report() -> Result<(), Box<dyn Error + Send + Sync>> {
+            Err(Unimock::new(
+                ErrorMock::source
+                    .returns_chain([std::io::Error::new(std::io::ErrorKind::Other, "disk full")])
+                    .with_message("write failed"),
+            )
+            .into())
+        }
+
+        let error = report().unwrap_err();
+        assert_eq!(vec!["write failed", "disk full"], messages(error.as_ref()));
+    }
+}
+
+mod io_script {
+    use std::io::{BufRead, ErrorKind, Read, Seek, SeekFrom, Write};
+
+    use unimock::{
+        mock::std::io::{BufReadMock, ReadMock, ReadScript, SeekMock, WriteMock, WriteScript},
+        *,
+    };
+
+    #[test]
+    fn read_fits_buffer_and_chunks() {
+        let script = ReadScript::new().chunk("hello").chunk("world");
+        let mut reader = Unimock::new(ReadMock::read.reads(&script));
+
+        let mut buf = [0; 3];
+        assert_eq!(3, reader.read(&mut buf).unwrap());
+        assert_eq!(b"hel", &buf);
+        assert_eq!(2, reader.read(&mut buf).unwrap());
+        assert_eq!(b"lo", &buf[..2]);
+        assert_eq!(3, reader.read(&mut buf).unwrap());
+        assert_eq!(b"wor", &buf);
+        assert_eq!(0, reader.read(&mut []).unwrap());
+        assert_eq!(2, reader.read(&mut buf).unwrap());
+        assert_eq!(0, reader.read(&mut buf).unwrap());
+        assert!(script.is_finished());
+    }
+
+    #[test]
+    fn read_errors_once() {
+        let script = ReadScript::new()
+            .chunk("ab")
+            .error(ErrorKind::WouldBlock)
+            .chunk("cd");
+        let mut reader = Unimock::new(ReadMock::read.reads(&script));
+
+        let mut buf = [0; 8];
+        assert_eq!(2, reader.read(&mut buf).unwrap());
+        assert_eq!(
+            ErrorKind::WouldBlock,
+            reader.read(&mut buf).unwrap_err().kind()
+        );
+        assert_eq!(2, reader.read(&mut buf).unwrap());
+        assert_eq!(b"cd", &buf[..2]);
+    }
+
+    #[test]
+    fn read_to_end_retries_interrupted() {
+        let script = ReadScript::new()
+            .chunk("ab")
+            .error(ErrorKind::Interrupted)
+            .chunk("cd");
+        let mut reader = Unimock::new(ReadMock::read.reads(&script));
+
+        let mut contents = vec![];
+        reader.read_to_end(&mut contents).unwrap();
+        assert_eq!(b"abcd", contents.as_slice());
+    }
+
+    #[test]
+    fn eof_marker_reads_empty_once() {
+        let script = ReadScript::new().chunk("a").eof().chunk("b");
+        let mut reader = Unimock::new(ReadMock::read.reads(&script));
+
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents).unwrap();
+        assert_eq!("a", contents);
+        assert!(!script.is_finished());
+
+        contents.clear();
+        reader.read_to_string(&mut contents).unwrap();
+        assert_eq!("b", contents);
+        assert!(script.is_finished());
+    }
+
+    #[test]
+    fn fill_buf_and_consume() {
+        let script = ReadScript::new()
+            .chunk("hello")
+            .error(ErrorKind::WouldBlock)
+            .chunk("world");
+        let mut reader = Unimock::new(BufReadMock::fill_buf.reads(&script));
+
+        assert_eq!(b"hello", reader.fill_buf().unwrap());
+        reader.consume(2);
+        assert_eq!(b"llo", reader.fill_buf().unwrap());
+        reader.consume(3);
+        assert_eq!(ErrorKind::WouldBlock, reader.fill_buf().unwrap_err().kind());
+        assert_eq!(b"world", reader.fill_buf().unwrap());
+        reader.consume(5);
+        assert_eq!(b"", reader.fill_buf().unwrap());
+    }
+
+    #[test]
+    fn read_lines() {
+        let script = ReadScript::new()
+            .chunk("first\nsec")
+            .error(ErrorKind::Interrupted)
+            .chunk("ond\n")
+            .chunk("third");
+        let reader = Unimock::new(BufReadMock::fill_buf.reads(&script));
+
+        let lines: Vec<String> = reader.lines().map(Result::unwrap).collect();
+        assert_eq!(vec!["first", "second", "third"], lines);
+    }
+
+    #[test]
+    fn read_and_seek_share_the_script() {
+        let script = ReadScript::new()
+            .chunk("hello ")
+            .error(ErrorKind::Interrupted)
+            .chunk("world");
+        let mut reader =
+            Unimock::new((ReadMock::read.reads(&script), SeekMock::seek.seeks(&script)));
+
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents).unwrap();
+        assert_eq!("hello world", contents);
+        assert_eq!(11, reader.stream_position().unwrap());
+
+        assert_eq!(6, reader.seek(SeekFrom::End(-5)).unwrap());
+        contents.clear();
+        reader.read_to_string(&mut contents).unwrap();
+        assert_eq!("world", contents);
+
+        assert_eq!(3, reader.seek(SeekFrom::Start(3)).unwrap());
+        assert_eq!(5, reader.seek(SeekFrom::Current(2)).unwrap());
+        let mut buf = [0; 8];
+        assert_eq!(1, reader.read(&mut buf).unwrap());
+        assert_eq!(
+            ErrorKind::Interrupted,
+            reader.read(&mut buf).unwrap_err().kind()
+        );
+
+        assert_eq!(42, reader.seek(SeekFrom::Start(42)).unwrap());
+        assert_eq!(0, reader.read(&mut buf).unwrap());
+        assert_eq!(42, reader.stream_position().unwrap());
+        assert_eq!(
+            ErrorKind::InvalidInput,
+            reader.seek(SeekFrom::Current(-43)).unwrap_err().kind()
+        );
+
+        assert_eq!(6, reader.seek(SeekFrom::Current(-36)).unwrap());
+        assert_eq!(5, reader.read(&mut buf).unwrap());
+        assert_eq!(b"world", &buf[..5]);
+    }
+
+    #[test]
+    fn write_records_bytes() {
+        let script = WriteScript::new()
+            .accept(2)
+            .error(ErrorKind::WouldBlock)
+            .accept(0);
+        let mut writer = Unimock::new(WriteMock::write.writes(&script));
+
+        assert_eq!(2, writer.write(b"hello").unwrap());
+        assert_eq!(
+            ErrorKind::WouldBlock,
+            writer.write(b"llo").unwrap_err().kind()
+        );
+        assert_eq!(
+            ErrorKind::WriteZero,
+            writer.write_all(b"llo").unwrap_err().kind()
+        );
+        assert!(script.is_finished());
+
+        writer.write_all(b"llo").unwrap();
+        assert_eq!(b"hello", script.written().as_slice());
+    }
+
+    #[test]
+    #[should_panic(expected = "Mock for BufRead::consume was never called.")]
+    fn unused_scripted_fn_fails_verification() {
+        let script = ReadScript::new().chunk("hello");
+        let mut reader = Unimock::new(BufReadMock::fill_buf.reads(&script));
+
+        assert_eq!(b"hello", reader.fill_buf().unwrap());
+    }
+}
+
+mod fs {
+    use std::path::Path;
+
+    use unimock::{
+        mock::std::fs::{FileSystem, FileSystemMock, StdFileSystem},
+        *,
+    };
+
+    fn copy(fs: &impl FileSystem, from: &Path, to: &Path) -> std::io::Result<()> {
+        let contents = fs.read(from)?;
+        fs.write(to, &contents)
+    }
+
+    #[test]
+    fn mocked() {
+        let fs = Unimock::new((
+            FileSystemMock::read
+                .next_call(matching!((path) if *path == Path::new("a")))
+                .returns(Ok(b"data".to_vec())),
+            FileSystemMock::write
+                .next_call(
+                    matching!((path, contents) if *path == Path::new("b") && *contents == b"data"),
+                )
+                .returns(Ok(())),
+        ));
+
+        copy(&fs, Path::new("a"), Path::new("b")).unwrap();
+    }
+
+    #[test]
+    fn partial_uses_std_fs() {
+        let dir = std::env::temp_dir().join(format!("unimock-fs-{}", std::process::id()));
+        let fs = Unimock::new_partial(
+            FileSystemMock::read
+                .next_call(matching!(_))
+                .returns(Ok(b"mocked".to_vec())),
+        );
+
+        fs.create_dir_all(&dir).unwrap();
+        copy(&fs, Path::new("nowhere"), &dir.join("file")).unwrap();
+        assert_eq!(
+            "mocked",
+            StdFileSystem.read_to_string(&dir.join("file")).unwrap()
+        );
+        assert_eq!(vec![dir.join("file")], fs.read_dir(&dir).unwrap());
+        fs.remove_file(&dir.join("file")).unwrap();
+        assert!(!fs.exists(&dir.join("file")));
+
+        std::fs::remove_dir(&dir).unwrap();
+    }
+}
+
+mod hash {
+    use std::hash::{BuildHasher, Hash, Hasher};
+
+    use unimock::{
+        mock::{core::hash::HasherMock, std::hash::BuildHasherMock},
+        *,
+    };
+
+    #[test]
+    fn build_hasher_shares_mock_state() {
+        let build_hasher = Unimock::new((
+            BuildHasherMock::build_hasher
+                .each_call(matching!())
+                .answers_ctx(|_, ctx| ctx.clone_instance()),
+            HasherMock::write_u32.each_call(matching!(42)).returns(()),
+            HasherMock::finish.each_call(matching!()).returns(7_u64),
+        ));
+
+        let mut hasher = build_hasher.build_hasher();
+        42_u32.hash(&mut hasher);
+        assert_eq!(7, hasher.finish());
+    }
+}
+
+mod net {
+    use std::net::{SocketAddr, ToSocketAddrs};
+
+    use unimock::{
+        mock::std::net::{Resolver, ResolverMock, StdResolver, ToSocketAddrsMock},
+        *,
+    };
+
+    #[test]
+    fn to_socket_addrs() {
+        let addr: SocketAddr = "127.0.0.1:80".parse().unwrap();
+        let unimock = Unimock::new(
+            ToSocketAddrsMock::to_socket_addrs
+                .next_call(matching!())
+                .returns(Ok(vec![addr].into_iter())),
+        );
+
+        assert_eq!(
+            vec![addr],
+            unimock.to_socket_addrs().unwrap().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn resolver() {
+        let addr: SocketAddr = "10.0.0.1:443".parse().unwrap();
+        let unimock = Unimock::new_partial(
+            ResolverMock::resolve
+                .some_call(matching!("example.com", 443))
+                .returns(Ok(vec![addr])),
+        );
+
+        assert_eq!(vec![addr], unimock.resolve("example.com", 443).unwrap());
+        // Not matched, so this is resolved by `StdResolver`:
+        assert_eq!(
+            StdResolver.resolve("127.0.0.1", 80).unwrap(),
+            unimock.resolve("127.0.0.1", 80).unwrap()
+        );
+    }
+}
+
+mod time {
+    use std::time::{Duration, Instant};
+
+    use unimock::{
+        mock::std::time::{Clock, ClockMock},
+        *,
+    };
+
+    fn timed(clock: &impl Clock, f: impl FnOnce()) -> Duration {
+        let start = clock.now();
+        f();
+        clock.now() - start
+    }
+
+    #[test]
+    fn mocked_clock() {
+        let start = Instant::now();
+        let clock = Unimock::new((
+            ClockMock::now.next_call(matching!()).returns(start),
+            ClockMock::sleep
+                .next_call(matching!((duration) if *duration == Duration::from_secs(60)))
+                .returns(()),
+            ClockMock::now
+                .next_call(matching!())
+                .returns(start + Duration::from_secs(60)),
+        ));
+
+        let elapsed = timed(&clock, || clock.sleep(Duration::from_secs(60)));
+        assert_eq!(Duration::from_secs(60), elapsed);
+    }
+
+    #[test]
+    fn partial_clock_sleeps() {
+        let clock = Unimock::new_partial(());
+        let elapsed = timed(&clock, || clock.sleep(Duration::from_millis(1)));
+        assert!(elapsed >= Duration::from_millis(1));
+    }
+}
+
+mod fmt_write {
+    use core::fmt::Write;
+
+    use unimock::{mock::core::fmt::WriteMock, *};
+
+    #[test]
+    fn write_str_and_fmt() {
+        let mut unimock = Unimock::new((
+            WriteMock::write_fmt
+                .next_call(matching!(_))
+                .default_implementation(),
+            WriteMock::write_str
+                .next_call(matching!("hello"))
+                .returns(Ok(())),
+            WriteMock::write_char
+                .next_call(matching!('!'))
+                .returns(Ok(())),
+            WriteMock::write_fmt
+                .next_call(matching!((args) if args.to_string() == "1 + 1"))
+                .returns(Err(core::fmt::Error)),
+        ));
+
+        // The default implementation of `write_fmt` calls `write_str`:
+        write!(&mut unimock, "hello").unwrap();
+        unimock.write_char('!').unwrap();
+        assert!(write!(&mut unimock, "{} + {}", 1, 1).is_err());
+    }
+}
+
 mod termination {
     use std::process::{ExitCode, Termination};
 
@@ -145,6 +940,7 @@ mod termination {
         Unimock::new(())
     }
 
+    #[allow(dead_code)]
     #[unimock(api=NonsenseMock)]
     trait Nonsense {
         fn nonsense(&self);
@@ -179,4 +975,98 @@ mod termination {
         );
         assert_eq!("ExitCode(unix_exit_status(0))", format!("{:?}", u.report()));
     }
+
+    mod report {
+        use std::process::{ExitCode, Termination};
+
+        use unimock::mock::std::process::{Report, TerminationMock};
+        use unimock::*;
+
+        #[unimock(api=ArgsMock)]
+        trait Args {
+            fn arg(&self, index: usize) -> Option<String>;
+        }
+
+        fn main_like(args: &impl Args) -> Result<(), String> {
+            match args.arg(1) {
+                Some(_) => Ok(()),
+                None => Err("missing argument".to_string()),
+            }
+        }
+
+        #[test]
+        fn compares_exit_codes() {
+            assert_eq!(Report::new(ExitCode::SUCCESS), ExitCode::SUCCESS);
+            assert_eq!(
+                Report::new(ExitCode::from(3)),
+                Report::new(ExitCode::from(3))
+            );
+            assert_ne!(Report::new(ExitCode::from(3)), ExitCode::FAILURE);
+            assert!(Report::new(ExitCode::SUCCESS).is_success());
+            assert!(Report::new(ExitCode::from(3)).is_failure());
+        }
+
+        #[test]
+        fn main_like_success() -> Unimock {
+            let args = Unimock::new(
+                ArgsMock::arg
+                    .next_call(matching!(1))
+                    .returns(Some("file".to_string())),
+            );
+            assert!(Report::new(main_like(&args)).is_success());
+            args
+        }
+
+        #[test]
+        fn main_like_failure() -> Unimock {
+            let args = Unimock::new(ArgsMock::arg.next_call(matching!(1)).returns(None));
+            assert_eq!(Report::new(main_like(&args)), ExitCode::FAILURE);
+            args
+        }
+
+        #[test]
+        fn mocked_unimock_termination() {
+            let u = Unimock::new(
+                TerminationMock::report
+                    .next_call(matching!())
+                    .returns(ExitCode::from(42))
+                    .once()
+                    .then()
+                    .unmocked(),
+            );
+            assert_eq!(Report::new(u.clone()), ExitCode::from(42));
+            assert!(Report::new(u).is_success());
+        }
+
+        #[test]
+        fn report_to_collects_errors() {
+            let mut output = String::new();
+            let exit_code = Unimock::new(ArgsMock::arg.next_call(matching!(_)).returns(None))
+                .report_to(&mut output);
+
+            assert_eq!(Report::new(exit_code), ExitCode::FAILURE);
+            let lines: Vec<&str> = output.lines().collect();
+            assert_eq!(2, lines.len());
+            assert!(lines[0].starts_with("unimock MockError: Args::arg: Expected Args::arg(_)"));
+            assert_eq!(
+                "unimock MockError: Mock for Args::arg was never called. Dead mocks should be removed.",
+                lines[1]
+            );
+        }
+
+        #[test]
+        fn report_to_success() {
+            let mut output = String::new();
+            let exit_code = Unimock::new(()).report_to(&mut output);
+
+            assert!(Report::new(exit_code).is_success());
+            assert!(output.is_empty());
+        }
+
+        #[test]
+        fn report_exit_code_of_termination() {
+            let exit_code: ExitCode = Report::new(Unimock::new(())).into();
+            assert_eq!(Report::new(exit_code.report()), ExitCode::SUCCESS);
+        }
+    }
 }
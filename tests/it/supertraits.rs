@@ -0,0 +1,67 @@
+use unimock::*;
+
+mod config {
+    use unimock::*;
+
+    #[unimock(api = ConfigMock)]
+    pub trait Config {
+        fn name(&self) -> &'static str;
+    }
+}
+
+#[unimock(api = LoggerMock)]
+trait Logger {
+    fn log(&self, msg: &str);
+}
+
+#[unimock(api = ServiceMock, supertraits = [config::Config = config::ConfigMock, Logger = LoggerMock])]
+trait Service: config::Config + Logger {
+    fn serve(&self) -> i32;
+}
+
+fn run(service: &impl Service) -> i32 {
+    service.log(service.name());
+    service.serve()
+}
+
+#[test]
+fn supertrait_mock_apis_are_reexported() {
+    let u = Unimock::new((
+        ServiceMock::Config::name
+            .next_call(matching!())
+            .returns("svc"),
+        ServiceMock::Logger::log
+            .next_call(matching!("svc"))
+            .returns(()),
+        ServiceMock::serve.next_call(matching!()).returns(42),
+    ));
+
+    assert_eq!(42, run(&u));
+}
+
+#[unimock(api = SourceMock)]
+trait Source<T: 'static> {
+    fn get(&self) -> T;
+}
+
+#[unimock(api = GenericServiceMock, supertraits = [Source<T>])]
+trait GenericService<T: 'static>: Source<T> {
+    fn len(&self) -> usize;
+}
+
+#[test]
+fn generic_supertrait() {
+    let u = Unimock::new((
+        SourceMock::get
+            .with_types::<i32>()
+            .next_call(matching!())
+            .returns(7),
+        GenericServiceMock::len
+            .with_types::<i32>()
+            .next_call(matching!())
+            .returns(1_usize),
+    ));
+
+    assert_eq!(7, u.get());
+    assert_eq!(1, GenericService::<i32>::len(&u));
+}
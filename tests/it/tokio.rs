@@ -0,0 +1,119 @@
+use core::task::Poll;
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader};
+use unimock::{
+    mock::tokio::io::{AsyncBufReadMock, AsyncReadMock, AsyncSeekMock, AsyncWriteMock},
+    *,
+};
+
+#[tokio::test]
+async fn test_async_read() {
+    let mut unimock = Unimock::new(AsyncReadMock::poll_read.next_call(matching!(_)).mutates(
+        |buf, _| {
+            buf.put_slice(b"ok");
+            Poll::Ready(Ok(()))
+        },
+    ));
+
+    let mut buf = [0; 4];
+    assert_eq!(2, unimock.read(&mut buf).await.unwrap());
+    assert_eq!(b"ok", &buf[..2]);
+}
+
+#[tokio::test]
+async fn test_async_buf_read() {
+    let mut unimock = Unimock::new((
+        AsyncBufReadMock::poll_fill_buf
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(b"line\nrest".to_vec()))),
+        AsyncBufReadMock::consume
+            .next_call(matching!(5))
+            .returns(()),
+    ));
+
+    let mut line = String::new();
+    assert_eq!(5, unimock.read_line(&mut line).await.unwrap());
+    assert_eq!("line\n", line);
+}
+
+#[tokio::test]
+async fn test_async_buf_reader() {
+    let mut reader = BufReader::new(Unimock::new((
+        AsyncReadMock::poll_read
+            .next_call(matching!(_))
+            .mutates(|buf, _| {
+                buf.put_slice(b"hello\n");
+                Poll::Ready(Ok(()))
+            }),
+        AsyncReadMock::poll_read
+            .next_call(matching!(_))
+            .mutates(|_, _| Poll::Ready(Ok(()))),
+    )));
+
+    let mut lines = vec![];
+    let mut line = String::new();
+    while reader.read_line(&mut line).await.unwrap() > 0 {
+        lines.push(core::mem::take(&mut line));
+    }
+    assert_eq!(vec!["hello\n"], lines);
+}
+
+#[tokio::test]
+async fn test_async_write() {
+    let mut unimock = Unimock::new((
+        AsyncWriteMock::poll_write
+            .next_call(matching!(_, eq!(b"hello")))
+            .returns(Poll::Ready(Ok(3))),
+        AsyncWriteMock::poll_write
+            .next_call(matching!(_, eq!(b"lo")))
+            .returns(Poll::Ready(Ok(2))),
+        AsyncWriteMock::poll_flush
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(()))),
+        AsyncWriteMock::poll_shutdown
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(()))),
+    ));
+
+    unimock.write_all(b"hello").await.unwrap();
+    unimock.flush().await.unwrap();
+    unimock.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_write_pending() {
+    let mut unimock = Unimock::new((
+        AsyncWriteMock::poll_write
+            .next_call(matching!(_, _))
+            .mutates(|cx, _| {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }),
+        AsyncWriteMock::poll_write
+            .next_call(matching!(_, _))
+            .returns(Poll::Ready(Ok(1))),
+    ));
+
+    assert_eq!(1, unimock.write(b"x").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_async_seek() {
+    let mut unimock = Unimock::new((
+        // `seek` first completes any seek already in progress:
+        AsyncSeekMock::poll_complete
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(0))),
+        AsyncSeekMock::start_seek
+            .next_call(matching!(std::io::SeekFrom::Start(42)))
+            .returns(Ok(())),
+        AsyncSeekMock::poll_complete
+            .next_call(matching!(_))
+            .returns(Poll::Ready(Ok(42))),
+    ));
+
+    assert_eq!(
+        42,
+        unimock.seek(std::io::SeekFrom::Start(42)).await.unwrap()
+    );
+}
@@ -6,6 +6,7 @@ use core::any::Any;
 mod unmock_simple {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock(api=SpyableMock, unmock_with=[repeat, concat])]
     trait Spyable {
         fn repeat(&self, arg: String) -> String;
@@ -66,7 +67,7 @@ mod unmock_simple {
 
     #[test]
     #[should_panic(
-        expected = "Spyable::concat: Expected Spyable::concat(\"\", \"\") at tests/it/unmock.rs:73 to match at least 1 call, but it actually matched no calls."
+        expected = "Spyable::concat: Expected Spyable::concat(\"\", \"\") at tests/it/unmock.rs:74 to match at least 1 call, but it actually matched no calls."
     )]
     fn unmatched_pattern_still_panics() {
         Unimock::new(SpyableMock::concat.stub(|each| {
@@ -140,6 +141,7 @@ async fn unmock_async() {
 mod unmock_with_custom_args {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock(unmock_with=[foo(b, a)])]
     trait FlippedOrder {
         fn foo(&self, a: u8, b: u16) -> u32;
@@ -153,6 +155,7 @@ mod unmock_with_custom_args {
 mod unmock_without_api_should_not_have_shadowing_problems_wrt_unmock_fns {
     use super::*;
 
+    #[allow(dead_code)]
     #[unimock(unmock_with=[foo])]
     trait Spyable {
         fn foo(&self);
@@ -160,3 +163,53 @@ mod unmock_without_api_should_not_have_shadowing_problems_wrt_unmock_fns {
 
     fn foo(_: &impl core::any::Any) {}
 }
+
+mod partial_by_default {
+    use super::*;
+
+    #[unimock(api=DefaultsMock, unmock_with=[real_port, _], partial_by_default=true)]
+    trait Defaults {
+        fn port(&self) -> u16;
+        fn host(&self) -> String;
+    }
+
+    fn real_port(_: &impl Any) -> u16 {
+        8080
+    }
+
+    #[test]
+    fn unmocked_in_strict_mock() {
+        assert_eq!(8080, Unimock::new(()).port());
+    }
+
+    #[test]
+    fn mocked_in_strict_mock() {
+        let u = Unimock::new(
+            DefaultsMock::port
+                .next_call(matching!())
+                .answers(|_| 443u16),
+        );
+        assert_eq!(443, u.port());
+    }
+
+    #[test]
+    #[should_panic(expected = "Defaults::host(): No mock implementation found.")]
+    fn methods_without_unmock_fn_are_strict() {
+        Unimock::new(()).host();
+    }
+}
+
+#[test]
+fn unmock_mut_self() {
+    #[unimock(api=CounterMock, unmock_with=[increment])]
+    trait Counter {
+        fn increment(&mut self, by: u32) -> u32;
+    }
+
+    fn increment(_: &mut impl Any, by: u32) -> u32 {
+        by + 1
+    }
+
+    let mut u = Unimock::new(CounterMock::increment.each_call(matching!(_)).unmocked());
+    assert_eq!(3, u.increment(2));
+}
@@ -5,7 +5,5 @@
 #![feature(impl_trait_in_assoc_type)]
 
 mod associated_future;
-mod async_fn_in_trait;
-mod rpit_future;
 
 fn main() {}
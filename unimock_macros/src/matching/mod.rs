@@ -40,18 +40,28 @@ enum ArgKind {
     Slice,
 }
 
-pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
+pub fn generate(mut input: MatchingInput) -> proc_macro2::TokenStream {
     if input.arg_patterns.is_empty() {
         return quote! {
             &|_m| {
                 _m.func(|_, _| true);
                 _m.pat_debug("()", file!(), line!());
+                _m.catch_all();
             }
         };
     }
 
-    let args = analyze_args(&input.arg_patterns);
     let pattern_debug_lit_str = generate_pat_debug(&input);
+    let mutation_index = match extract_mutation_patterns(&mut input.arg_patterns) {
+        Ok(mutation_index) => mutation_index,
+        Err(err) => return err.to_compile_error(),
+    };
+    let args = analyze_args(&input.arg_patterns);
+    let catch_all = if is_catch_all(&input) {
+        Some(quote! { _m.catch_all(); })
+    } else {
+        None
+    };
     let mut global_guards = vec![];
 
     if let Some((_, expr)) = input.guard {
@@ -76,6 +86,27 @@ pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
     });
     let arg_expr = concat_args_parenthesized(&args, |arg| arg.render_expr());
 
+    // The mutation is not part of the inputs, so it is received as a separate closure parameter:
+    let (func_ident, closure_params) = match mutation_index {
+        Some(mutation_index) => {
+            let mutation_ident = &args[mutation_index].arg_ident;
+            let inputs_pat = concat_args_parenthesized(&args, |arg| {
+                if &arg.arg_ident == mutation_ident {
+                    quote! { _ }
+                } else {
+                    let arg_ident = &arg.arg_ident;
+                    quote! { #arg_ident }
+                }
+            });
+
+            (
+                quote! { mutation_func },
+                quote! { #inputs_pat, #mutation_ident, reporter },
+            )
+        }
+        None => (quote! { func }, quote! { #arg_pat, reporter }),
+    };
+
     let diagnostics_arm = if global_guards.is_empty() {
         Some(generate_diagnostics_arm(&arg_pattern_arms, &args))
     } else {
@@ -84,14 +115,18 @@ pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
         None
     };
 
+    let has_snapshot = arg_pattern_arms
+        .iter()
+        .flat_map(|arm| &arm.arg_matchers)
+        .any(ArgMatcher::is_snapshot);
     let success_arms = arg_pattern_arms
         .iter()
-        .map(|match_arm| match_arm.render_success_arm(&global_guards));
+        .map(|match_arm| match_arm.render_success_arm(&global_guards, has_snapshot));
 
     quote! {
         &|_m| {
-            _m.func(
-                |#arg_pat, reporter| {
+            _m.#func_ident(
+                |#closure_params| {
                     #(#local_defs)*
                     match #arg_expr {
                         #(#success_arms)*
@@ -101,10 +136,55 @@ pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
                 }
             );
             _m.pat_debug(#pattern_debug_lit_str, file!(), line!());
+            #catch_all
         }
     }
 }
 
+/// Replace `mutation!(pat)` with `pat`, returning the argument index of the mutation.
+fn extract_mutation_patterns(arg_patterns: &mut [ArgPattern]) -> syn::Result<Option<usize>> {
+    let mut mutation_index: Option<usize> = None;
+
+    for arg_pattern in arg_patterns.iter_mut() {
+        for (index, pat) in arg_pattern.tuple.elems.iter_mut().enumerate() {
+            let pat_macro = match pat {
+                syn::Pat::Macro(pat_macro) if pat_macro.mac.path.is_ident("mutation") => pat_macro,
+                _ => continue,
+            };
+
+            match mutation_index {
+                Some(existing) if existing != index => {
+                    return Err(syn::Error::new(
+                        pat_macro.mac.path.span(),
+                        "mutation!() must be at the same argument position in all patterns",
+                    ));
+                }
+                _ => {
+                    mutation_index = Some(index);
+                }
+            }
+
+            let inner =
+                syn::parse::Parser::parse2(syn::Pat::parse_multi, pat_macro.mac.tokens.clone())?;
+            *pat = inner;
+        }
+    }
+
+    Ok(mutation_index)
+}
+
+// Whether the matcher accepts any inputs, i.e. has an all-wildcard pattern and no guard
+fn is_catch_all(input: &MatchingInput) -> bool {
+    input.guard.is_none()
+        && input.arg_patterns.iter().any(|arg_pattern| {
+            arg_pattern
+                .tuple
+                .elems
+                .iter()
+                .all(|pat| matches!(pat, syn::Pat::Wild(_)))
+        })
+}
+
 // An arm (or, _candidate_) for a complete arg match (all patterns)
 struct ArgPatternArm {
     arg_matchers: Vec<ArgMatcher>,
@@ -129,8 +209,17 @@ impl ArgPatternArm {
             .filter_map(|arg_matcher| arg_matcher.render_local_def())
     }
 
-    fn render_success_arm(&self, global_guards: &[TokenStream]) -> proc_macro2::TokenStream {
-        let mut concatenated_guards = Vec::from_iter(global_guards);
+    fn render_success_arm(
+        &self,
+        global_guards: &[TokenStream],
+        has_snapshot: bool,
+    ) -> proc_macro2::TokenStream {
+        // Snapshot writes recorded by a failed arm must not be committed when a later arm succeeds:
+        let snapshot_arm = if has_snapshot {
+            Some(quote! { ::unimock::private::snapshot_arm(reporter) })
+        } else {
+            None
+        };
 
         let local_guards = self
             .arg_matchers
@@ -138,6 +227,8 @@ impl ArgPatternArm {
             .filter_map(|m| m.render_guard())
             .collect::<Vec<_>>();
 
+        let mut concatenated_guards = Vec::from_iter(&snapshot_arm);
+        concatenated_guards.extend(global_guards);
         concatenated_guards.extend(&local_guards);
 
         let if_guard = if !concatenated_guards.is_empty() {
@@ -196,6 +287,16 @@ impl ArgMatcher {
         }
     }
 
+    fn is_snapshot(&self) -> bool {
+        matches!(
+            self,
+            Self::Compare(CompareMatcher {
+                compare_macro: CompareMacro::Snapshot,
+                ..
+            })
+        )
+    }
+
     fn render_local_def(&self) -> Option<proc_macro2::TokenStream> {
         match self {
             Self::Pattern(_) => None,
@@ -223,15 +324,11 @@ impl ArgMatcher {
     fn render_guard(&self) -> Option<proc_macro2::TokenStream> {
         match self {
             Self::Pattern(_) => None,
-            Self::Compare(compare_matcher) => {
-                let span = compare_matcher.span;
-                let pat_bind_ident = &compare_matcher.pat_bind_ident;
-                let local_ident = &compare_matcher.local_ident;
-                let operator = compare_matcher.compare_macro.operator(span);
-                Some(quote_spanned! { span=>
-                    (#pat_bind_ident #operator #local_ident)
-                })
-            }
+            Self::Compare(compare_matcher) => Some(compare_matcher.compare_macro.render_check(
+                compare_matcher.span,
+                &compare_matcher.pat_bind_ident,
+                &compare_matcher.local_ident,
+            )),
         }
     }
 
@@ -261,21 +358,39 @@ impl ArgMatcher {
             },
             ArgMatcher::Compare(compare_matcher) => {
                 let span = compare_matcher.span;
-                let operator = compare_matcher.compare_macro.operator(span);
                 let local_ident = &compare_matcher.local_ident;
-
-                let reporter_method = syn::Ident::new(
-                    match &compare_matcher.compare_macro {
-                        CompareMacro::Eq => "eq_fail",
-                        CompareMacro::Ne => "ne_fail",
+                let check =
+                    compare_matcher
+                        .compare_macro
+                        .render_check(span, &arg_expr, local_ident);
+
+                let report = match &compare_matcher.compare_macro {
+                    CompareMacro::Eq | CompareMacro::Ne => {
+                        let reporter_method = syn::Ident::new(
+                            match &compare_matcher.compare_macro {
+                                CompareMacro::Ne => "ne_fail",
+                                _ => "eq_fail",
+                            },
+                            span,
+                        );
+
+                        quote! {
+                            use ::unimock::private::{ProperDebug, NoDebug};
+                            reporter.#reporter_method(#index, #arg_expr.unimock_try_debug(), #local_ident.unimock_try_debug());
+                        }
+                    }
+                    CompareMacro::Snapshot => quote! {
+                        reporter.snapshot_fail(
+                            #index,
+                            Some(::unimock::private::snapshot_debug(#arg_expr)),
+                            ::unimock::private::snapshot_expected(::core::env!("CARGO_MANIFEST_DIR"), #local_ident),
+                        );
                     },
-                    span,
-                );
+                };
 
                 Some(quote! {
-                    if !(#arg_expr #operator #local_ident) {
-                        use ::unimock::private::{ProperDebug, NoDebug};
-                        reporter.#reporter_method(#index, #arg_expr.unimock_try_debug(), #local_ident.unimock_try_debug());
+                    if !#check {
+                        #report
                     }
                 })
             }
@@ -321,6 +436,7 @@ fn generate_diagnostics_arm(arms: &[ArgPatternArm], args: &[Arg]) -> proc_macro2
 enum CompareMacro {
     Eq,
     Ne,
+    Snapshot,
 }
 
 impl CompareMacro {
@@ -329,20 +445,31 @@ impl CompareMacro {
             Some(Self::Eq)
         } else if path.is_ident("ne") {
             Some(Self::Ne)
+        } else if path.is_ident("snapshot") {
+            Some(Self::Snapshot)
         } else {
             None
         }
     }
 
-    fn operator(&self, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+    /// Render a boolean expression checking the actual value against the macro argument
+    fn render_check(
+        &self,
+        span: proc_macro2::Span,
+        actual: &impl quote::ToTokens,
+        local: &syn::Ident,
+    ) -> proc_macro2::TokenStream {
         match self {
-            Self::Eq => quote_spanned! { span=> == },
-            Self::Ne => quote_spanned! { span=> != },
+            Self::Eq => quote_spanned! { span=> (#actual == #local) },
+            Self::Ne => quote_spanned! { span=> (#actual != #local) },
+            Self::Snapshot => quote_spanned! { span=>
+                ::unimock::private::snapshot_matches(reporter, ::core::env!("CARGO_MANIFEST_DIR"), #local, #actual)
+            },
         }
     }
 }
 
-fn concat_args_parenthesized<F>(args: &Vec<Arg>, f: F) -> proc_macro2::TokenStream
+fn concat_args_parenthesized<F>(args: &[Arg], f: F) -> proc_macro2::TokenStream
 where
     F: Fn(&Arg) -> proc_macro2::TokenStream,
 {
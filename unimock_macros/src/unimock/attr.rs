@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use quote::ToTokens;
+
 use super::trait_info::TraitInfo;
 
 /// Parsed unimock attribute
@@ -12,8 +14,15 @@ pub struct Attr {
     pub associated_consts: HashMap<String, syn::TraitItemConst>,
     unmocks: Option<WithSpan<Vec<Unmock>>>,
     pub mirror: Option<syn::Path>,
+    /// Local newtype around Unimock to implement the trait for, instead of Unimock itself
+    pub wrapper: Option<syn::Path>,
+    pub supertraits: Vec<Supertrait>,
     pub input_lifetime: syn::Lifetime,
     pub debug: bool,
+    /// Unmocked methods call their `unmock_with` function also in strict mocks
+    pub partial_by_default: bool,
+    pub dyn_mock: bool,
+    method_arg_attrs: Vec<Vec<ArgAttr>>,
 }
 
 pub struct WithSpan<T>(pub T, pub proc_macro2::Span);
@@ -29,7 +38,51 @@ impl Attr {
         })
     }
 
+    /// The type the trait gets implemented for
+    pub fn self_type(&self) -> syn::Path {
+        match &self.wrapper {
+            Some(wrapper) => wrapper.clone(),
+            None => {
+                let prefix = &self.prefix;
+                syn::parse_quote! { #prefix::Unimock }
+            }
+        }
+    }
+
+    pub fn get_arg_attrs(&self, method_index: usize) -> &[ArgAttr] {
+        self.method_arg_attrs
+            .get(method_index)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Extract `#[unimock(..)]` attributes on method parameters, removing them from the trait.
+    pub fn extract_arg_attrs(&mut self, item_trait: &mut syn::ItemTrait) -> syn::Result<()> {
+        for item in &mut item_trait.items {
+            if let syn::TraitItem::Fn(method) = item {
+                let mut arg_attrs = vec![];
+
+                for fn_arg in &mut method.sig.inputs {
+                    let attrs = match fn_arg {
+                        syn::FnArg::Receiver(receiver) => &mut receiver.attrs,
+                        syn::FnArg::Typed(pat_type) => &mut pat_type.attrs,
+                    };
+
+                    arg_attrs.push(ArgAttr::extract(attrs)?);
+                }
+
+                self.method_arg_attrs.push(arg_attrs);
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn validate(&self, trait_info: &TraitInfo) -> syn::Result<()> {
+        for supertrait in &self.supertraits {
+            supertrait.validate(trait_info, &self.mock_api)?;
+        }
+
         match &self.unmocks {
             Some(unmocked) if unmocked.0.len() != trait_info.methods.len() => {
                 return Err(syn::Error::new(
@@ -52,7 +105,11 @@ impl syn::parse::Parse for Attr {
         let mut associated_consts = HashMap::default();
         let mut unmocks = None;
         let mut debug = false;
+        let mut partial_by_default = None;
+        let mut dyn_mock = None;
         let mut mirror = None;
+        let mut wrapper = None;
+        let mut supertraits = vec![];
 
         while !input.is_empty() {
             if input.peek(syn::token::Type) {
@@ -106,11 +163,34 @@ impl syn::parse::Parse for Attr {
                         let _: syn::token::Eq = input.parse()?;
                         debug = input.parse::<syn::LitBool>()?.value;
                     }
+                    "partial_by_default" => {
+                        let _: syn::token::Eq = input.parse()?;
+                        let lit = input.parse::<syn::LitBool>()?;
+                        partial_by_default = Some(lit);
+                    }
+                    "dyn_mock" => {
+                        let _: syn::token::Eq = input.parse()?;
+                        let lit = input.parse::<syn::LitBool>()?;
+                        dyn_mock = Some(lit);
+                    }
                     "mirror" => {
                         let _: syn::token::Eq = input.parse()?;
                         let path: syn::Path = input.parse()?;
                         mirror = Some(path);
                     }
+                    "wrapper" => {
+                        let _: syn::token::Eq = input.parse()?;
+                        wrapper = Some(input.parse()?);
+                    }
+                    "supertraits" => {
+                        let _: syn::token::Eq = input.parse()?;
+
+                        let content;
+                        let _ = syn::bracketed!(content in input);
+                        let entries =
+                            content.parse_terminated(Supertrait::parse, syn::token::Comma)?;
+                        supertraits.extend(entries);
+                    }
                     _ => return Err(syn::Error::new(keyword.span(), "Unrecognized keyword")),
                 }
 
@@ -122,6 +202,24 @@ impl syn::parse::Parse for Attr {
             }
         }
 
+        if let Some(lit) = &partial_by_default {
+            if lit.value && unmocks.is_none() {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "partial_by_default requires unmock_with",
+                ));
+            }
+        }
+
+        if let (Some(lit), Some(_)) = (&dyn_mock, &wrapper) {
+            if lit.value {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "dyn_mock can't be combined with wrapper, because Unimock does not implement the trait",
+                ));
+            }
+        }
+
         Ok(Self {
             prefix: prefix.unwrap_or_else(|| syn::parse_quote! { ::unimock }),
             mock_api,
@@ -129,8 +227,13 @@ impl syn::parse::Parse for Attr {
             associated_consts,
             unmocks,
             mirror,
+            wrapper,
+            supertraits,
             input_lifetime: syn::Lifetime::new("'__i", proc_macro2::Span::call_site()),
             debug,
+            partial_by_default: partial_by_default.map(|lit| lit.value).unwrap_or(false),
+            dyn_mock: dyn_mock.map(|lit| lit.value).unwrap_or(false),
+            method_arg_attrs: vec![],
         })
     }
 }
@@ -161,6 +264,78 @@ impl FlattenedMethods {
     }
 }
 
+/// A supertrait listed in `supertraits=[..]`
+pub struct Supertrait {
+    /// Path to the supertrait, as written in the trait's supertrait bounds
+    pub path: syn::Path,
+    /// Optional path to the supertrait's existing mock API module, for re-exporting
+    pub mock_api: Option<syn::Path>,
+}
+
+impl Supertrait {
+    fn validate(&self, trait_info: &TraitInfo, mock_api: &MockApi) -> syn::Result<()> {
+        let is_supertrait = trait_info
+            .input_trait
+            .supertraits
+            .iter()
+            .any(|bound| match bound {
+                syn::TypeParamBound::Trait(trait_bound) => {
+                    is_same_trait(&trait_bound.path, &self.path)
+                }
+                _ => false,
+            });
+
+        if !is_supertrait {
+            let path = &self.path;
+            return Err(syn::Error::new_spanned(
+                path,
+                format!(
+                    "`{}` is not a supertrait of `{}`",
+                    quote::quote!(#path),
+                    trait_info.input_trait.ident
+                ),
+            ));
+        }
+
+        if let (Some(supertrait_api), MockApi::Flattened(_) | MockApi::Hidden) =
+            (&self.mock_api, mock_api)
+        {
+            return Err(syn::Error::new_spanned(
+                supertrait_api,
+                "Re-exporting a supertrait mock API requires a mock API module, i.e. `api=Ident`",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl syn::parse::Parse for Supertrait {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let path: syn::Path = input.parse()?;
+        let mock_api = if input.peek(syn::token::Eq) {
+            let _: syn::token::Eq = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { path, mock_api })
+    }
+}
+
+/// Compare by the last segment, so that `Config` matches a bound written as `crate::Config`.
+fn is_same_trait(bound: &syn::Path, listed: &syn::Path) -> bool {
+    match (bound.segments.last(), listed.segments.last()) {
+        (Some(a), Some(b)) => {
+            a.ident == b.ident
+                && a.arguments.to_token_stream().to_string()
+                    == b.arguments.to_token_stream().to_string()
+        }
+        _ => false,
+    }
+}
+
 pub struct Unmock(Option<UnmockFn>);
 
 pub struct UnmockFn {
@@ -208,3 +383,59 @@ impl syn::parse::Parse for Unmock {
 pub struct UnmockFnParams {
     pub params: syn::punctuated::Punctuated<syn::Expr, syn::token::Comma>,
 }
+
+/// Parsed unimock attribute on a method parameter
+#[derive(Clone, Default)]
+pub struct ArgAttr {
+    /// Function used for formatting the argument instead of its `Debug` implementation
+    pub debug_with: Option<syn::Path>,
+    /// Whether to hide the argument value in diagnostics
+    pub redact: bool,
+}
+
+impl ArgAttr {
+    fn extract(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Self> {
+        let mut arg_attr = Self::default();
+        let mut result = Ok(());
+
+        attrs.retain(|attribute| {
+            if !attribute.path().is_ident("unimock") {
+                return true;
+            }
+
+            if result.is_ok() {
+                result = attribute.parse_args_with(|input: syn::parse::ParseStream| {
+                    arg_attr.parse_keywords(input)
+                });
+            }
+
+            false
+        });
+
+        result.map(|_| arg_attr)
+    }
+
+    fn parse_keywords(&mut self, input: syn::parse::ParseStream) -> syn::Result<()> {
+        while !input.is_empty() {
+            let keyword: syn::Ident = input.parse()?;
+            match keyword.to_string().as_str() {
+                "debug_with" => {
+                    let _: syn::token::Eq = input.parse()?;
+                    self.debug_with = Some(input.parse()?);
+                }
+                "redact" => {
+                    self.redact = true;
+                }
+                _ => return Err(syn::Error::new(keyword.span(), "Unrecognized keyword")),
+            }
+
+            if input.peek(syn::token::Comma) {
+                let _: syn::token::Comma = input.parse()?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -5,8 +5,10 @@ use quote::quote;
 use syn::spanned::Spanned;
 use syn::visit_mut::VisitMut;
 
-use super::attr::MockApi;
-use super::util::{DotAwait, GenericParamsWithBounds, IsGeneric, IsTypeGeneric, RpitFuture};
+use super::attr::{ArgAttr, MockApi};
+use super::util::{
+    DotAwait, GenericParamsWithBounds, IsGeneric, IsTypeGeneric, RpitBoxed, RpitFuture,
+};
 use super::Attr;
 use super::{output, util};
 
@@ -29,6 +31,7 @@ pub struct MockMethod<'t> {
     pub has_default_impl: bool,
     pub output_structure: output::OutputStructure,
     pub mutated_arg: Option<MutatedArg>,
+    arg_attrs: Vec<ArgAttr>,
     mirrored_attr_indexes: Vec<usize>,
 }
 
@@ -52,7 +55,7 @@ pub enum InputsSyntax {
 pub enum ArgClass<'m, 't> {
     Receiver,
     MutMutated(&'m MutatedArg, &'t syn::PatIdent),
-    MutImpossible(&'t syn::PatIdent, &'t syn::Type),
+    MutImpossible(&'t syn::PatIdent),
     Other(&'t syn::PatIdent, &'t syn::Type),
     Unprocessable(&'t syn::PatType),
 }
@@ -97,7 +100,7 @@ impl<'t> MockMethod<'t> {
         syntax: InputsSyntax,
         tupled: Tupled,
         attr: &'t Attr,
-    ) -> InputsDestructuring {
+    ) -> InputsDestructuring<'_> {
         InputsDestructuring {
             method: self,
             syntax,
@@ -144,7 +147,7 @@ impl<'t> MockMethod<'t> {
             .find(|fn_arg| matches!(fn_arg, syn::FnArg::Typed(_)));
 
         let body = if first_param.is_some() {
-            let inputs_try_debug_exprs = self.inputs_try_debug_exprs();
+            let inputs_try_debug_exprs = self.inputs_try_debug_exprs(attr);
             quote! {
                 use #prefix::private::{ProperDebug, NoDebug};
                 #prefix::private::lib::vec![#(#inputs_try_debug_exprs),*]
@@ -162,7 +165,10 @@ impl<'t> MockMethod<'t> {
         })
     }
 
-    pub fn inputs_try_debug_exprs(&self) -> impl Iterator<Item = proc_macro2::TokenStream> + 't {
+    pub fn inputs_try_debug_exprs<'s>(
+        &'s self,
+        attr: &'s Attr,
+    ) -> impl Iterator<Item = proc_macro2::TokenStream> + 's {
         self.method
             .sig
             .inputs
@@ -171,9 +177,18 @@ impl<'t> MockMethod<'t> {
             .filter_map(|(index, fn_arg)| match fn_arg {
                 syn::FnArg::Receiver(_) => None,
                 syn::FnArg::Typed(pat_type) => match (index, pat_type.pat.as_ref()) {
-                    (_, syn::Pat::Ident(pat_ident)) => {
-                        Some(try_debug_expr(pat_ident, &pat_type.ty))
-                    }
+                    (_, syn::Pat::Ident(pat_ident)) => match self.arg_attrs.get(index) {
+                        Some(ArgAttr { redact: true, .. }) => {
+                            let prefix = &attr.prefix;
+                            let ident = &pat_ident.ident;
+                            Some(quote! { #prefix::private::redacted_debug(#ident) })
+                        }
+                        Some(ArgAttr {
+                            debug_with: Some(debug_with),
+                            ..
+                        }) => Some(debug_with_expr(pat_ident, &pat_type.ty, debug_with, attr)),
+                        _ => Some(try_debug_expr(pat_ident, &pat_type.ty)),
+                    },
                     _ => Some(
                         syn::Error::new(pat_type.span(), "Unprocessable argument")
                             .to_compile_error(),
@@ -182,6 +197,25 @@ impl<'t> MockMethod<'t> {
             })
     }
 
+    /// Indexes (in the Inputs tuple) of the arguments that must not be shown in diagnostics
+    pub fn redacted_input_indexes(&self) -> Vec<usize> {
+        self.method
+            .sig
+            .inputs
+            .iter()
+            .zip(self.arg_attrs.iter())
+            .filter(|(fn_arg, _)| matches!(fn_arg, syn::FnArg::Typed(_)))
+            .enumerate()
+            .filter_map(|(input_index, (_, arg_attr))| {
+                if arg_attr.redact {
+                    Some(input_index)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
     pub fn mockfn_doc_attrs(&self, trait_path: &syn::Path) -> Vec<proc_macro2::TokenStream> {
         let ident = &self.method.sig.ident;
         let sig_string = doc::signature_documentation(&self.method.sig, doc::SkipReceiver(true));
@@ -215,7 +249,7 @@ impl<'t> MockMethod<'t> {
                     }
                     (_, syn::Pat::Ident(pat_ident), _) => {
                         if Self::is_mutable_reference_with_lifetimes_in_type(pat_type.ty.as_ref()) {
-                            ArgClass::MutImpossible(pat_ident, &pat_type.ty)
+                            ArgClass::MutImpossible(pat_ident)
                         } else {
                             ArgClass::Other(pat_ident, &pat_type.ty)
                         }
@@ -276,20 +310,33 @@ pub fn extract_methods<'s>(
         })
         .enumerate()
         .map(|(index, method)| {
-            match determine_mockable(method) {
+            match determine_mockable(method, attr) {
                 Mockable::Yes => {}
                 Mockable::Skip => return Ok(None),
                 Mockable::Err(err) => return Err(err),
             };
 
             let mut adapted_sig = method.sig.clone();
-            let adapt_sig_result = adapt_sig(&mut adapted_sig);
+            let adapt_sig_result = adapt_sig(&mut adapted_sig)?;
+
+            if let (Some(rpit_boxed), Some(_)) = (&adapt_sig_result.rpit_boxed, &method.default) {
+                // The default body's value gets boxed, which requires the hidden type to be 'static:
+                if !rpit_boxed.bounds.iter().any(|bound| {
+                    matches!(bound, syn::TypeParamBound::Lifetime(lifetime) if lifetime.ident == "static")
+                }) {
+                    return Err(syn::Error::new_spanned(
+                        &method.sig.output,
+                        "A method with a default body returning `impl Trait` must bound the return type by `'static`, e.g. `impl Iterator<Item = u8> + 'static`, for unimock to delegate to the default body.",
+                    ));
+                }
+            }
             let is_type_generic =
                 IsTypeGeneric(is_trait_type_generic.0 || adapt_sig_result.is_type_generic.0);
 
             let output_structure = output::determine_output_structure(
                 &adapted_sig,
                 adapt_sig_result.rpit_future,
+                adapt_sig_result.rpit_boxed,
                 item_trait,
                 attr,
             );
@@ -332,13 +379,27 @@ pub fn extract_methods<'s>(
                         (pat.as_ref(), ty.as_ref())
                     {
                         if type_ref.mutability.is_some() {
+                            let mut ty = util::substitute_lifetimes(
+                                type_ref.elem.as_ref().clone(),
+                                &syn::parse_quote!('m),
+                            );
+
+                            // The elided object lifetime of `&mut dyn Trait` is the lifetime of the reference:
+                            if let syn::Type::TraitObject(trait_object) = &mut ty {
+                                if !trait_object
+                                    .bounds
+                                    .iter()
+                                    .any(|bound| matches!(bound, syn::TypeParamBound::Lifetime(_)))
+                                {
+                                    trait_object.bounds.push(syn::parse_quote!('m));
+                                    ty = syn::parse_quote!((#ty));
+                                }
+                            }
+
                             mutated_arg = Some(MutatedArg {
                                 index,
                                 ident: pat_ident.ident.clone(),
-                                ty: util::substitute_lifetimes(
-                                    type_ref.elem.as_ref().clone(),
-                                    &syn::parse_quote!('m),
-                                ),
+                                ty,
                             })
                         }
                     }
@@ -365,12 +426,13 @@ pub fn extract_methods<'s>(
                 },
                 mock_fn_ident: generate_mock_fn_ident(method, index, is_type_generic, attr)?,
                 ident_lit: syn::LitStr::new(
-                    &format!("{}", &method.sig.ident),
+                    &format!("{}", method.sig.ident),
                     method.sig.ident.span(),
                 ),
                 has_default_impl: method.default.is_some(),
                 output_structure,
                 mutated_arg,
+                arg_attrs: attr.get_arg_attrs(index).to_vec(),
                 mirrored_attr_indexes,
             }))
         })
@@ -383,7 +445,7 @@ enum Mockable {
     Err(syn::Error),
 }
 
-fn determine_mockable(method: &syn::TraitItemFn) -> Mockable {
+fn determine_mockable(method: &syn::TraitItemFn, attr: &Attr) -> Mockable {
     fn is_receiver(first_fn_arg: Option<&syn::FnArg>) -> bool {
         match first_fn_arg {
             None => false,
@@ -398,7 +460,13 @@ fn determine_mockable(method: &syn::TraitItemFn) -> Mockable {
 
     let first_fn_arg = method.sig.inputs.first();
 
-    if is_receiver(first_fn_arg) {
+    if attr.wrapper.is_some() && method.default.is_some() {
+        // A wrapper can't delegate to the default implementation of a foreign trait:
+        Mockable::Err(syn::Error::new(
+            method.sig.ident.span(),
+            "Provided methods can't be mocked through a wrapper, because there is no way to delegate to the foreign default implementation. Omit provided methods when using wrapper.",
+        ))
+    } else if is_receiver(first_fn_arg) {
         Mockable::Yes
     } else if method.default.is_some() {
         // method is provided, skip
@@ -464,6 +532,30 @@ fn try_debug_expr(pat_ident: &syn::PatIdent, ty: &syn::Type) -> proc_macro2::Tok
     }
 }
 
+// Format the argument using a custom formatter function, which accepts a reference to the innermost referenced type
+fn debug_with_expr(
+    pat_ident: &syn::PatIdent,
+    ty: &syn::Type,
+    debug_with: &syn::Path,
+    attr: &Attr,
+) -> proc_macro2::TokenStream {
+    fn count_refs(ty: &syn::Type) -> usize {
+        match ty {
+            syn::Type::Reference(type_reference) => 1 + count_refs(&type_reference.elem),
+            _ => 0,
+        }
+    }
+
+    let prefix = &attr.prefix;
+    let ident = &pat_ident.ident;
+    // the argument is bound by reference, so dereference it one extra time:
+    let derefs = (0..=count_refs(ty)).map(|_| quote! { * });
+
+    quote! {
+        #prefix::private::debug_with(&#(#derefs)* #ident, #debug_with)
+    }
+}
+
 pub struct InputsDestructuring<'t> {
     method: &'t MockMethod<'t>,
     syntax: InputsSyntax,
@@ -500,7 +592,7 @@ impl<'t> quote::ToTokens for InputsDestructuring<'t> {
                     ArgClass::Receiver => {
                         continue;
                     }
-                    ArgClass::MutMutated(_, pat_ident) | ArgClass::MutImpossible(pat_ident, _) => {
+                    ArgClass::MutMutated(_, pat_ident) | ArgClass::MutImpossible(pat_ident) => {
                         match self.syntax {
                             InputsSyntax::FnPattern
                             | InputsSyntax::FnParams
@@ -540,10 +632,11 @@ struct AdaptSigResult {
     is_type_generic: IsTypeGeneric,
     impl_trait_idents: HashSet<String>,
     rpit_future: Option<RpitFuture>,
+    rpit_boxed: Option<RpitBoxed>,
 }
 
 // TODO: Rewrite impl Trait to normal param
-fn adapt_sig(sig: &mut syn::Signature) -> AdaptSigResult {
+fn adapt_sig(sig: &mut syn::Signature) -> syn::Result<AdaptSigResult> {
     let mut generics: syn::Generics = Default::default();
     let mut impl_trait_idents: HashSet<String> = HashSet::new();
     std::mem::swap(&mut sig.generics, &mut generics);
@@ -551,17 +644,28 @@ fn adapt_sig(sig: &mut syn::Signature) -> AdaptSigResult {
     struct ImplTraitConverter<'s> {
         /// state
         cur_is_return: bool,
+        cur_is_nested: bool,
 
         /// output
         generics: &'s mut syn::Generics,
         impl_trait_idents: &'s mut HashSet<String>,
         impl_trait_count: usize,
         rpit_future: Option<RpitFuture>,
+        rpit_boxed: Option<RpitBoxed>,
+        error: Option<syn::Error>,
     }
 
     impl<'s> syn::visit_mut::VisitMut for ImplTraitConverter<'s> {
         fn visit_type_mut(&mut self, ty: &mut syn::Type) {
             if let syn::Type::ImplTrait(impl_trait) = ty {
+                if self.cur_is_nested {
+                    self.error.get_or_insert(syn::Error::new_spanned(
+                        &*impl_trait,
+                        "Unsupported: `impl Trait` nested inside the return type. Only the whole return type can be `impl Trait`.",
+                    ));
+                    return;
+                }
+
                 if let Some(future_bound) = find_future_bound(impl_trait.bounds.iter()) {
                     if self.cur_is_return {
                         self.rpit_future = Some(RpitFuture {
@@ -571,6 +675,24 @@ fn adapt_sig(sig: &mut syn::Signature) -> AdaptSigResult {
                     }
                 }
 
+                if self.cur_is_return {
+                    // The boxed value must be 'static, so non-'static lifetime bounds are dropped:
+                    self.rpit_boxed = Some(RpitBoxed {
+                        bounds: impl_trait
+                            .bounds
+                            .iter()
+                            .filter(|bound| match bound {
+                                syn::TypeParamBound::Lifetime(lifetime) => {
+                                    lifetime.ident == "static"
+                                }
+                                _ => true,
+                            })
+                            .cloned()
+                            .collect(),
+                    });
+                    return;
+                }
+
                 let generic_ident = quote::format_ident!("ImplTrait{}", self.impl_trait_count);
 
                 self.impl_trait_idents.insert(generic_ident.to_string());
@@ -589,6 +711,11 @@ fn adapt_sig(sig: &mut syn::Signature) -> AdaptSigResult {
                 *ty = syn::parse_quote!( #generic_ident );
 
                 self.impl_trait_count += 1;
+            } else if self.cur_is_return {
+                // Look for `impl Trait` nested inside the return type, e.g. `Option<impl Trait>`:
+                let cur_is_nested = std::mem::replace(&mut self.cur_is_nested, true);
+                syn::visit_mut::visit_type_mut(self, ty);
+                self.cur_is_nested = cur_is_nested;
             }
         }
 
@@ -601,31 +728,43 @@ fn adapt_sig(sig: &mut syn::Signature) -> AdaptSigResult {
 
     let mut converter = ImplTraitConverter {
         cur_is_return: false,
+        cur_is_nested: false,
         generics: &mut generics,
         impl_trait_idents: &mut impl_trait_idents,
         impl_trait_count: 0,
         rpit_future: None,
+        rpit_boxed: None,
+        error: None,
     };
     converter.visit_signature_mut(sig);
 
+    if let Some(error) = converter.error {
+        return Err(error);
+    }
+
     let rpit_future = converter.rpit_future;
+    let rpit_boxed = converter.rpit_boxed;
 
     // write back generics
     std::mem::swap(&mut generics, &mut sig.generics);
 
     let mut is_type_generic = IsTypeGeneric(false);
     for generic_param in &sig.generics.params {
-        if matches!(generic_param, syn::GenericParam::Type(_)) {
+        if matches!(
+            generic_param,
+            syn::GenericParam::Type(_) | syn::GenericParam::Const(_)
+        ) {
             is_type_generic.0 = true;
         }
     }
 
-    AdaptSigResult {
+    Ok(AdaptSigResult {
         is_generic: IsGeneric(!sig.generics.params.is_empty()),
         is_type_generic,
         impl_trait_idents,
         rpit_future,
-    }
+        rpit_boxed,
+    })
 }
 
 pub enum Receiver {
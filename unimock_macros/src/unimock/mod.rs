@@ -5,6 +5,7 @@ mod associated_future;
 mod attr;
 mod method;
 mod output;
+mod supertrait;
 mod trait_info;
 mod util;
 
@@ -19,7 +20,12 @@ use attr::{UnmockFn, UnmockFnParams};
 use self::method::{ArgClass, MockMethod};
 use self::util::{iter_generic_type_params, InferImplTrait};
 
-pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macro2::TokenStream> {
+pub fn generate(
+    mut attr: Attr,
+    mut item_trait: syn::ItemTrait,
+) -> syn::Result<proc_macro2::TokenStream> {
+    attr.extract_arg_attrs(&mut item_trait)?;
+
     let trait_info = trait_info::TraitInfo::analyze(&item_trait, &attr)?;
     attr.validate(&trait_info)?;
 
@@ -44,7 +50,8 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
     let mock_fn_defs: Vec<Option<MockFnDef>> = trait_info
         .methods
         .iter()
-        .map(|method| def_mock_fn(method.as_ref(), &trait_info, &attr))
+        .enumerate()
+        .map(|(index, method)| def_mock_fn(index, method.as_ref(), &trait_info, &attr))
         .collect();
     let associated_futures = trait_info
         .methods
@@ -133,11 +140,13 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
                 syn::LitStr::new(&impl_doc_string, proc_macro2::Span::call_site());
 
             let vis = &trait_info.input_trait.vis;
+            let supertrait_reexports = supertrait::def_supertrait_reexports(&trait_info, &attr);
             (
                 Some(quote! {
                     #[doc = #mod_doc_lit_str]
                     #[allow(non_snake_case)]
                     #vis mod #module_ident {
+                        #supertrait_reexports
                         #(#mock_fn_struct_items)*
                     }
                 }),
@@ -185,7 +194,34 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
         None
     };
 
+    let dyn_mock_impls = if attr.dyn_mock {
+        let auto_traits = [quote! {}, quote! { + Send }, quote! { + Send + Sync }];
+        let dyn_mock_impls = auto_traits.iter().map(|auto_traits| {
+            // Generic over the implementor, to avoid trivially unsatisfiable auto trait bounds:
+            let mut params = util::trait_param_list(&trait_info);
+            params.push(quote! {
+                __U: #trait_path #generic_args #auto_traits + 'static
+            });
+
+            quote! {
+                impl<#(#params),*> #prefix::private::DynMock<__U> for dyn #trait_path #generic_args #auto_traits #where_clause {
+                    fn boxed(value: __U) -> #prefix::private::lib::Box<Self> {
+                        #prefix::private::lib::Box::new(value)
+                    }
+                }
+            }
+        });
+
+        Some(quote! {
+            #(#dyn_mock_impls)*
+        })
+    } else {
+        None
+    };
+
     let output_trait = trait_info.output_trait;
+    let self_type = attr.self_type();
+    let supertrait_assertions = supertrait::def_supertrait_assertions(&trait_info, &attr);
 
     Ok(quote! {
         #output_trait
@@ -198,7 +234,7 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
 
             #impl_doc
             #(#impl_attributes)*
-            impl #generic_params #trait_path #generic_args for #prefix::Unimock #where_clause {
+            impl #generic_params #trait_path #generic_args for #self_type #where_clause {
                 #(#attr_associated_types)*
                 #(#attr_associated_consts)*
                 #(#associated_futures)*
@@ -206,6 +242,8 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
             }
 
             #default_impl_delegator
+            #dyn_mock_impls
+            #supertrait_assertions
         };
     })
 }
@@ -216,6 +254,7 @@ struct MockFnDef {
 }
 
 fn def_mock_fn(
+    index: usize,
     method: Option<&method::MockMethod>,
     trait_info: &TraitInfo,
     attr: &Attr,
@@ -248,7 +287,7 @@ fn def_mock_fn(
 
     let generic_params = util::Generics::fn_params(trait_info, Some(method));
     let generic_args = util::Generics::fn_args(trait_info, Some(method), InferImplTrait(false));
-    let where_clause = &trait_info.input_trait.generics.where_clause;
+    let where_clause = &trait_info.mock_fn_where_clause;
 
     let doc_attrs = if matches!(attr.mock_api, attr::MockApi::Hidden) {
         vec![]
@@ -275,7 +314,37 @@ fn def_mock_fn(
         None
     };
 
+    let info_set_partial_by_default =
+        if attr.partial_by_default && attr.get_unmock_fn(index).is_some() {
+            Some(quote! { .partial_by_default() })
+        } else {
+            None
+        };
+
+    let redacted_input_indexes = method.redacted_input_indexes();
+    let info_set_redacted_inputs = if redacted_input_indexes.is_empty() {
+        None
+    } else {
+        Some(quote! { .redact_inputs(&[#(#redacted_input_indexes),*]) })
+    };
+
+    let rpit_boxed_item = match &method.output_structure.wrapping {
+        output::OutputWrapping::RpitBoxed { ident, bounds } => Some(quote! {
+            #[allow(non_camel_case_types)]
+            pub struct #ident(#prefix::private::lib::Box<dyn #bounds + Send + Sync>);
+
+            impl<T: #bounds + Send + Sync + 'static> ::core::convert::From<T> for #ident {
+                fn from(value: T) -> Self {
+                    Self(#prefix::private::lib::Box::new(value))
+                }
+            }
+        }),
+        _ => None,
+    };
+
     let impl_block = quote_spanned! { span=>
+        #rpit_boxed_item
+
         #(#mirrored_attrs)*
         impl #generic_params #prefix::MockFn for #mock_fn_path #generic_args #where_clause {
             type Inputs<#input_lifetime> = #input_types_tuple;
@@ -287,6 +356,8 @@ fn def_mock_fn(
                 #prefix::MockFnInfo::new::<Self>()
                     .path(#trait_ident_lit, #method_ident_lit)
                     #info_set_default_impl
+                    #info_set_partial_by_default
+                    #info_set_redacted_inputs
             }
 
             #debug_inputs_fn
@@ -296,6 +367,7 @@ fn def_mock_fn(
     let mock_fn_def = if let Some(non_generic_ident) = &method.non_generic_mock_entry_ident {
         // the trait is generic
         let phantoms_tuple = util::MockFnPhantomsTuple { trait_info, method };
+        let struct_generic_params = util::Generics::fn_struct_params(trait_info, Some(method));
         let untyped_phantoms =
             iter_generic_type_params(trait_info, method).map(|_| util::UntypedPhantomData);
         let module_scope = match &attr.mock_api {
@@ -307,20 +379,23 @@ fn def_mock_fn(
             mock_fn_struct_item: gen_mock_fn_struct_item(non_generic_ident),
             impl_details: quote! {
                 impl #module_scope #non_generic_ident {
+                    /// Select the generic parameters of the mocked function.
+                    #[allow(clippy::multiple_bound_locations)]
                     pub fn with_types #generic_params(
                         self
-                    ) -> impl for<#input_lifetime> #prefix::MockFn<
+                    ) -> impl for<#input_lifetime, 'm> #prefix::MockFn<
                         Inputs<#input_lifetime> = #input_types_tuple,
+                        Mutation<'m> = #mutation,
                         Response = #response_associated_type,
                     >
                         #where_clause
                     {
-                        #mock_fn_ident(#(#untyped_phantoms),*)
+                        #mock_fn_ident::#generic_args(#(#untyped_phantoms),*)
                     }
                 }
 
                 #[allow(non_camel_case_types)]
-                struct #mock_fn_ident #generic_args #phantoms_tuple;
+                struct #mock_fn_ident #struct_generic_params #phantoms_tuple;
 
                 #impl_block
             },
@@ -359,16 +434,31 @@ fn def_method_impl(
     let mock_fn_path = method.mock_fn_path(attr);
 
     let receiver = method.receiver();
-    let self_ref = SelfReference(&receiver);
+    let self_ref = match (&attr.wrapper, &receiver) {
+        (Some(_), Receiver::Ref | Receiver::Owned) => quote! { &self.0 },
+        _ => SelfReference(&receiver).to_token_stream(),
+    };
     let self_to_delegator = SelfToDelegator(&receiver);
     let eval_generic_args = util::Generics::fn_args(trait_info, Some(method), InferImplTrait(true));
 
+    // The mock produces the boxing newtype, while the method returns the box inside it:
+    let opt_rpit_boxed = match &method.output_structure.wrapping {
+        output::OutputWrapping::RpitBoxed { ident, bounds } => Some((ident, bounds)),
+        _ => None,
+    };
+    let rpit_unbox = |expr: proc_macro2::TokenStream| match opt_rpit_boxed {
+        Some((ident, _)) => quote! { #ident::from(#expr).0 },
+        None => expr,
+    };
+    let opt_rpit_field = opt_rpit_boxed.map(|_| quote! { .0 });
+
     let must_async_wrap = matches!(
         method.output_structure.wrapping,
-        output::OutputWrapping::RpitFuture(_) | output::OutputWrapping::AssociatedFuture(_)
+        output::OutputWrapping::RpitFuture | output::OutputWrapping::AssociatedFuture(_)
     );
 
     let trait_path = &trait_info.trait_path;
+    let trait_generic_args = util::Generics::trait_args(trait_info, None, InferImplTrait(false));
     let method_ident = &method_sig.ident;
     let opt_dot_await = method.opt_dot_await();
     let track_caller = if method.method.sig.asyncness.is_none() {
@@ -381,7 +471,7 @@ fn def_method_impl(
 
     let body = match kind {
         MethodImplKind::Mock => {
-            let unmock_arm = attr.get_unmock_fn(index).map(
+            let unmock = attr.get_unmock_fn(index).map(
                 |UnmockFn {
                      path: unmock_path,
                      params: unmock_params,
@@ -389,26 +479,41 @@ fn def_method_impl(
                     let fn_params =
                         method.inputs_destructuring(InputsSyntax::FnParams, Tupled(false), attr);
 
+                    // `self` has been moved into the surrogate by the method prelude
+                    let unmock_self = match (&receiver, &attr.wrapper) {
+                        (Receiver::MutRef { surrogate_self }, None)
+                            if !method.output_structure.ownership.borrows_mut() =>
+                        {
+                            quote! { #surrogate_self }
+                        }
+                        _ => quote! { self },
+                    };
+
                     let unmock_expr = match unmock_params {
                         None => quote! {
-                            #unmock_path(self, #fn_params) #opt_dot_await
+                            #unmock_path(#unmock_self, #fn_params) #opt_dot_await
                         },
                         Some(UnmockFnParams { params }) => quote! {
                             #unmock_path(#params) #opt_dot_await
                         },
                     };
 
+                    let unmock_expr = rpit_unbox(unmock_expr);
+
                     let eval_pattern = method.inputs_destructuring(
                         InputsSyntax::EvalPatternMutAsWildcard,
                         Tupled(true),
                         attr,
                     );
 
-                    quote! {
-                        #prefix::private::Evaluation::Unmocked(#eval_pattern) => #unmock_expr,
-                    }
+                    (eval_pattern, unmock_expr)
                 },
             );
+            let unmock_arm = unmock.as_ref().map(|(eval_pattern, unmock_expr)| {
+                quote! {
+                    #prefix::private::Evaluation::Unmocked(#eval_pattern) => #unmock_expr,
+                }
+            });
 
             let inputs_eval_params =
                 method.inputs_destructuring(InputsSyntax::EvalParams, Tupled(true), attr);
@@ -452,18 +557,76 @@ fn def_method_impl(
                     _ => todo!("unhandled DefaultImplDelegator constructor"),
                 };
 
-                Some(quote! {
-                    <#delegator_path as #trait_path>::#method_ident(
+                let call = quote! {
+                    <#delegator_path as #trait_path #trait_generic_args>::#method_ident(
                         #delegator_constructor,
                         #fn_params
                     )
                         #opt_dot_await
-                })
+                };
+
+                Some(rpit_unbox(call))
             } else {
                 None
             };
 
             match &receiver {
+                // The output is converted after evaluation, with exclusive access to the instance.
+                // Evaluation only borrows `self` immutably, so each arm can borrow it mutably afterwards.
+                Receiver::MutRef { surrogate_self } | Receiver::Pin { surrogate_self }
+                    if method.output_structure.ownership.borrows_mut() =>
+                {
+                    let field = attr.wrapper.as_ref().map(|_| quote! { .0 });
+                    let (eval_self, mut_self) = match (&receiver, &field) {
+                        (Receiver::MutRef { .. }, None) => (quote! { &*self }, quote! { self }),
+                        (Receiver::MutRef { .. }, Some(field)) => {
+                            (quote! { &self #field }, quote! { &mut self #field })
+                        }
+                        (_, None) => (
+                            quote! { &*self },
+                            quote! { ::core::pin::Pin::into_inner(self) },
+                        ),
+                        (_, Some(field)) => (
+                            quote! { &self #field },
+                            quote! { &mut ::core::pin::Pin::into_inner(self) #field },
+                        ),
+                    };
+
+                    let unmock_arm = unmock.as_ref().map(|(eval_pattern, unmock_expr)| {
+                        quote! {
+                            #prefix::private::Evaluation::Unmocked(#eval_pattern) => {
+                                return #unmock_expr;
+                            }
+                        }
+                    });
+                    let default_impl_delegate_arm = default_delegator_call.as_ref().map(|call| {
+                        let eval_pattern = method.inputs_destructuring(
+                            InputsSyntax::EvalPatternMutAsWildcard,
+                            Tupled(true),
+                            attr,
+                        );
+                        quote! {
+                            #prefix::private::Evaluation::CallDefaultImpl(#eval_pattern) => {
+                                let #surrogate_self = #mut_self;
+                                return #call;
+                            }
+                        }
+                    });
+
+                    quote_spanned! { span=>
+                        let __response = match #prefix::private::eval::<#mock_fn_path #eval_generic_args>(#eval_self, #inputs_eval_params, #mutated_param) {
+                            #unmock_arm
+                            #default_impl_delegate_arm
+                            e => e.unwrap(#eval_self)
+                        };
+                        #prefix::private::output_mut::<#mock_fn_path #eval_generic_args>(#mut_self, __response)
+                    }
+                }
+                _ if method.output_structure.ownership.borrows_mut() => {
+                    quote_spanned! { span=>
+                        ::core::compile_error!("Outputs that borrow mutably from self require a `&mut self` receiver")
+                    }
+                }
                 Receiver::MutRef { .. } | Receiver::Pin { .. } => {
                     let default_impl_delegate_arm_polonius = if method.method.default.is_some() {
                         let eval_pattern = method.inputs_destructuring(
@@ -482,19 +645,33 @@ fn def_method_impl(
                         None
                     };
 
-                    let polonius_return_type: syn::Type = match method.method.sig.output.clone() {
-                        syn::ReturnType::Default => syn::parse_quote!(()),
-                        syn::ReturnType::Type(_arrow, ty) => {
-                            util::substitute_lifetimes(*ty, &syn::parse_quote!('polonius))
-                        }
+                    // With a default impl arm exiting the closure, the unmocked output has to be returned explicitly:
+                    let unmock_arm_polonius = match (&unmock, method.method.default.is_some()) {
+                        (Some((eval_pattern, unmock_expr)), true) => Some(quote! {
+                            #prefix::private::Evaluation::Unmocked(#eval_pattern) => {
+                                #prefix::polonius::_return!(#unmock_expr);
+                            },
+                        }),
+                        _ => unmock_arm.clone(),
                     };
 
+                    let polonius_return_type: syn::Type =
+                        match (method.method.sig.output.clone(), opt_rpit_boxed) {
+                            (_, Some((_, bounds))) => syn::parse_quote! {
+                                #prefix::private::lib::Box<dyn #bounds + Send + Sync>
+                            },
+                            (syn::ReturnType::Default, None) => syn::parse_quote!(()),
+                            (syn::ReturnType::Type(_arrow, ty), None) => {
+                                util::substitute_lifetimes(*ty, &syn::parse_quote!('polonius))
+                            }
+                        };
+
                     let polonius = quote_spanned! { span=>
                         #prefix::polonius::_polonius!(|#self_ref| -> #polonius_return_type {
                             match #prefix::private::eval::<#mock_fn_path #eval_generic_args>(#self_ref, #inputs_eval_params, #mutated_param) {
-                                #unmock_arm
+                                #unmock_arm_polonius
                                 #default_impl_delegate_arm_polonius
-                                e => #prefix::polonius::_return!(e.unwrap(#self_ref))
+                                e => #prefix::polonius::_return!(e.unwrap(#self_ref) #opt_rpit_field)
                             }
                         })
                     };
@@ -534,7 +711,7 @@ fn def_method_impl(
                         match #prefix::private::eval::<#mock_fn_path #eval_generic_args>(#self_ref, #inputs_eval_params, #mutated_param) {
                             #unmock_arm
                             #default_impl_delegate_arm
-                            e => e.unwrap(#self_ref)
+                            e => e.unwrap(#self_ref) #opt_rpit_field
                         }
                     }
                 }
@@ -579,7 +756,7 @@ fn def_method_impl(
                 _ => panic!("BUG: Incompatible receiver for default delegator"),
             };
             quote! {
-                <#prefix::Unimock as #trait_path>::#method_ident(
+                <#prefix::Unimock as #trait_path #trait_generic_args>::#method_ident(
                     #unimock_accessor,
                     #inputs_destructuring
                 )
@@ -588,33 +765,53 @@ fn def_method_impl(
         }
     };
 
+    // With a wrapper, the surrogate self is the inner Unimock:
+    let opt_wrapper_field = attr.wrapper.as_ref().map(|_| quote! { .0 });
+
     let body = match (kind, &receiver) {
-        (MethodImplKind::Mock, Receiver::MutRef { surrogate_self }) => {
-            quote! {
+        // The mutably borrowing body binds the surrogate self by itself:
+        (MethodImplKind::Mock, _) if method.output_structure.ownership.borrows_mut() => body,
+        (MethodImplKind::Mock, Receiver::MutRef { surrogate_self }) => match opt_wrapper_field {
+            Some(field) => quote! {
+                let mut #surrogate_self = &mut self #field;
+                #body
+            },
+            None => quote! {
                 let mut #surrogate_self = self;
                 #body
-            }
-        }
-        (MethodImplKind::Mock, Receiver::Pin { surrogate_self }) => {
-            quote! {
+            },
+        },
+        (MethodImplKind::Mock, Receiver::Pin { surrogate_self }) => match opt_wrapper_field {
+            Some(field) => quote! {
+                let mut #surrogate_self = &mut ::core::pin::Pin::into_inner(self) #field;
+                #body
+            },
+            None => quote! {
                 let mut #surrogate_self = ::core::pin::Pin::into_inner(self);
                 #body
-            }
-        }
+            },
+        },
         _ => body,
     };
 
-    let body = if must_async_wrap {
-        quote_spanned! { span=>
-            async move { #body }
-        }
+    let (body, allow_manual_async) = if must_async_wrap {
+        (
+            quote_spanned! { span=>
+                async move { #body }
+            },
+            // The signature is mirrored from the trait, so it can't be rewritten to `async fn`:
+            Some(quote! {
+                #[allow(clippy::manual_async_fn)]
+            }),
+        )
     } else {
-        body
+        (body, None)
     };
 
     quote_spanned! { span=>
         #(#mirrored_attrs)*
         #track_caller
+        #allow_manual_async
         #[allow(unused)]
         #method_sig {
             #body
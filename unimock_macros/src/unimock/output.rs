@@ -3,7 +3,7 @@ use quote::quote;
 use syn::{parse_quote, visit_mut::VisitMut};
 
 use super::{
-    util::{self, find_future_bound, RpitFuture},
+    util::{self, find_future_bound, RpitBoxed, RpitFuture},
     Attr,
 };
 
@@ -49,16 +49,22 @@ impl OutputStructure {
 
 pub enum OutputWrapping {
     None,
-    RpitFuture(syn::Type),
-    AssociatedFuture(syn::TraitItemType),
+    RpitFuture,
+    AssociatedFuture(Box<syn::TraitItemType>),
+    RpitBoxed {
+        ident: syn::Ident,
+        bounds: syn::punctuated::Punctuated<syn::TypeParamBound, syn::token::Plus>,
+    },
 }
 
 pub enum OutputOwnership {
     Owned,
     SelfReference,
+    SelfMutReference,
     ParamReference,
     StaticReference,
     Mixed,
+    MixedMut,
 }
 
 impl OutputOwnership {
@@ -70,19 +76,50 @@ impl OutputOwnership {
         match self {
             Self::Owned => "Owned",
             Self::SelfReference => "Borrowed",
+            Self::SelfMutReference => "BorrowedMut",
             Self::ParamReference => "StaticRef",
             Self::StaticReference => "StaticRef",
-            Self::Mixed => "Mixed",
+            Self::Mixed | Self::MixedMut => "Mixed",
         }
     }
+
+    /// Whether the output mutably borrows from `self`, and must be converted with exclusive access.
+    pub fn borrows_mut(&self) -> bool {
+        matches!(self, Self::SelfMutReference | Self::MixedMut)
+    }
 }
 
 pub fn determine_output_structure(
     sig: &syn::Signature,
     rpit_future: Option<RpitFuture>,
+    rpit_boxed: Option<RpitBoxed>,
     item_trait: &syn::ItemTrait,
     attr: &Attr,
 ) -> OutputStructure {
+    if let Some(expanded_ty) = expand_generic_associated_type(&sig.output, attr) {
+        let mut expanded_sig = sig.clone();
+        expanded_sig.output = syn::ReturnType::Type(Default::default(), Box::new(expanded_ty));
+
+        return determine_output_structure(
+            &expanded_sig,
+            rpit_future,
+            rpit_boxed,
+            item_trait,
+            attr,
+        );
+    }
+
+    if let Some(RpitBoxed { bounds }) = rpit_boxed {
+        let ident = quote::format_ident!("UnimockRpit__{}__{}", item_trait.ident, sig.ident);
+
+        return OutputStructure {
+            response_ty: AssociatedInnerType::Typed(parse_quote! { #ident }),
+            output_ty: AssociatedInnerType::Typed(parse_quote! { #ident }),
+            wrapping: OutputWrapping::RpitBoxed { ident, bounds },
+            ownership: OutputOwnership::Owned,
+        };
+    }
+
     match &sig.output {
         syn::ReturnType::Default => OutputStructure {
             wrapping: OutputWrapping::None,
@@ -98,6 +135,17 @@ pub fn determine_output_structure(
                 let borrow_info = ReturnTypeAnalyzer::analyze_borrows(sig, &mut inner_ty);
                 let ownership = determine_reference_ownership(sig, type_reference);
 
+                if let (OutputOwnership::SelfReference, Some(_)) =
+                    (&ownership, type_reference.mutability)
+                {
+                    return OutputStructure {
+                        wrapping: OutputWrapping::None,
+                        ownership: OutputOwnership::SelfMutReference,
+                        response_ty: AssociatedInnerType::new_static(inner_ty, &borrow_info),
+                        output_ty: AssociatedInnerType::SameAsResponse,
+                    };
+                }
+
                 OutputStructure {
                     wrapping: OutputWrapping::None,
                     ownership: determine_reference_ownership(sig, type_reference),
@@ -128,7 +176,7 @@ pub fn determine_output_structure(
                         attr,
                     );
 
-                    output_structure.wrapping = OutputWrapping::RpitFuture(rpit_future.output.ty);
+                    output_structure.wrapping = OutputWrapping::RpitFuture;
                     output_structure
                 } else {
                     determine_owned_or_mixed_output_structure(sig, output_ty, item_trait, attr)
@@ -154,61 +202,34 @@ pub fn determine_owned_or_mixed_output_structure(
 
     let ownership = if borrow_info.has_input_lifetime {
         OutputOwnership::Owned
-    } else if borrow_info.has_elided_reference || borrow_info.has_self_reference {
+    } else if borrow_info.borrows_from_self() {
         OutputOwnership::Mixed
     } else {
         OutputOwnership::Owned
     };
 
-    match (ownership, inner_ty) {
-        (OutputOwnership::Mixed, syn::Type::Tuple(tuple)) => {
-            let mut response_ty_tuple = syn::TypeTuple {
-                paren_token: syn::token::Paren::default(),
-                elems: Default::default(),
-            };
-            let mut output_ty_tuple = syn::TypeTuple {
-                paren_token: syn::token::Paren::default(),
-                elems: Default::default(),
-            };
-
-            for elem in tuple.elems {
-                match elem {
-                    syn::Type::Reference(reference) => {
-                        let elem = reference.elem;
-
-                        if reference.mutability.is_some() {
-                            panic!("TODO: Mutable references in tuples");
-                        }
-
-                        response_ty_tuple.elems.push(parse_quote! {
-                            #prefix::output::Borrowed<#elem>
-                        });
-                        output_ty_tuple.elems.push(parse_quote! {
-                            #prefix::output::Borrowed<#elem>
-                        });
+    match ownership {
+        OutputOwnership::Mixed => {
+            let mut borrows_mut = false;
+            let (ownership, response_ty) =
+                match MixedPart::analyze(sig, inner_ty, prefix, &mut borrows_mut) {
+                    MixedPart::Mixed(container) if borrows_mut => {
+                        (OutputOwnership::MixedMut, container)
                     }
-                    owned => {
-                        response_ty_tuple.elems.push(parse_quote! {
-                            #prefix::output::Owned<#owned>
-                        });
-                        output_ty_tuple.elems.push(parse_quote! {
-                            #prefix::output::Owned<#owned>
-                        });
+                    MixedPart::Mixed(container) => (OutputOwnership::Mixed, container),
+                    MixedPart::Owned(ty) | MixedPart::Borrowed(ty) | MixedPart::BorrowedMut(ty) => {
+                        (OutputOwnership::Owned, ty)
                     }
-                }
-            }
-
-            response_ty_tuple.elems.push_punct(Default::default());
-            output_ty_tuple.elems.push_punct(Default::default());
+                };
 
             OutputStructure {
                 wrapping: OutputWrapping::None,
-                ownership: OutputOwnership::Mixed,
-                response_ty: AssociatedInnerType::Typed(syn::Type::Tuple(response_ty_tuple)),
-                output_ty: AssociatedInnerType::Typed(syn::Type::Tuple(output_ty_tuple)),
+                ownership,
+                response_ty: AssociatedInnerType::Typed(Box::new(response_ty)),
+                output_ty: AssociatedInnerType::SameAsResponse,
             }
         }
-        (ownership, inner_ty) => {
+        ownership => {
             let response_ty = AssociatedInnerType::new_static(inner_ty, &borrow_info);
             let output_ty = AssociatedInnerType::new_gat(output_ty, &borrow_info, &ownership);
 
@@ -222,6 +243,278 @@ pub fn determine_owned_or_mixed_output_structure(
     }
 }
 
+/// One part of a mixed output, described by the response type that the part is stored as.
+///
+/// E.g. `Result<&T, E>` is a `Mixed` part with the container `Result<Borrowed<T>, Owned<E>>`.
+enum MixedPart {
+    Owned(syn::Type),
+    Borrowed(syn::Type),
+    BorrowedMut(syn::Type),
+    Mixed(syn::Type),
+}
+
+impl MixedPart {
+    fn analyze(
+        sig: &syn::Signature,
+        mut ty: syn::Type,
+        prefix: &syn::Path,
+        borrows_mut: &mut bool,
+    ) -> Self {
+        let borrow_info = ReturnTypeAnalyzer::analyze_borrows(sig, &mut ty);
+        if !borrow_info.borrows_from_self() {
+            return Self::Owned(static_type(ty));
+        }
+
+        match ty {
+            syn::Type::Paren(paren) => Self::analyze(sig, *paren.elem, prefix, borrows_mut),
+            syn::Type::Reference(reference) => {
+                let elem = match *reference.elem {
+                    syn::Type::Paren(paren) => static_type(*paren.elem),
+                    elem => static_type(elem),
+                };
+
+                if reference.mutability.is_some() {
+                    *borrows_mut = true;
+                    Self::BorrowedMut(elem)
+                } else {
+                    Self::Borrowed(elem)
+                }
+            }
+            syn::Type::Tuple(mut tuple) => {
+                tuple.elems = std::mem::take(&mut tuple.elems)
+                    .into_iter()
+                    .map(|elem| {
+                        Self::analyze(sig, elem, prefix, borrows_mut).into_descriptor(prefix)
+                    })
+                    .collect();
+                if !tuple.elems.empty_or_trailing() {
+                    tuple.elems.push_punct(Default::default());
+                }
+
+                Self::Mixed(syn::Type::Tuple(tuple))
+            }
+            syn::Type::Path(mut type_path) if type_path.qself.is_none() => {
+                let original = syn::Type::Path(type_path.clone());
+
+                // A `Cow<'_, T>` is stored as owned and output as borrowed.
+                if is_std_cow(&type_path.path) {
+                    return Self::Mixed(static_type(original));
+                }
+
+                let Some(last_segment) = type_path.path.segments.last_mut() else {
+                    return Self::Owned(static_type(original));
+                };
+
+                let syn::PathArguments::AngleBracketed(generic_args) = &mut last_segment.arguments
+                else {
+                    return Self::Owned(static_type(original));
+                };
+
+                let mut is_container = false;
+                for arg in generic_args.args.iter_mut() {
+                    match arg {
+                        syn::GenericArgument::Type(arg_ty) => {
+                            let part = Self::analyze(sig, arg_ty.clone(), prefix, borrows_mut);
+                            is_container |= !matches!(part, Self::Owned(_));
+                            *arg_ty = part.into_descriptor(prefix);
+                        }
+                        syn::GenericArgument::Lifetime(lifetime) => {
+                            *lifetime = syn::Lifetime::new("'static", lifetime.span());
+                        }
+                        _ => {}
+                    }
+                }
+
+                // A `Result` alias with a fixed error type, like `io::Result<T>`:
+                if is_container && last_segment.ident == "Result" && generic_args.args.len() == 1 {
+                    let mut unit_result = original.clone();
+                    if let syn::Type::Path(unit_result) = &mut unit_result {
+                        if let Some(segment) = unit_result.path.segments.last_mut() {
+                            segment.arguments =
+                                syn::PathArguments::AngleBracketed(parse_quote! { <()> });
+                        }
+                    }
+                    let ok_ty = &generic_args.args[0];
+                    let error_ty = static_type(parse_quote! {
+                        <#unit_result as #prefix::private::ResultAlias>::Error
+                    });
+
+                    return Self::Mixed(parse_quote! {
+                        ::core::result::Result<#ok_ty, #prefix::output::Owned<#error_ty>>
+                    });
+                }
+
+                if is_container {
+                    Self::Mixed(syn::Type::Path(type_path))
+                } else {
+                    // Only lifetime arguments are borrowed from self, e.g. `Foo<'_>`.
+                    // This relies on the type being covariant in its lifetimes.
+                    Self::Owned(static_type(original))
+                }
+            }
+            other => Self::Owned(static_type(other)),
+        }
+    }
+
+    fn into_descriptor(self, prefix: &syn::Path) -> syn::Type {
+        match self {
+            Self::Owned(ty) => parse_quote! { #prefix::output::Owned<#ty> },
+            Self::Borrowed(ty) => parse_quote! { #prefix::output::Borrowed<#ty> },
+            Self::BorrowedMut(ty) => parse_quote! { #prefix::output::BorrowedMut<#ty> },
+            Self::Mixed(ty) => parse_quote! { #prefix::output::Mixed<#ty> },
+        }
+    }
+}
+
+/// Whether the path names `std::borrow::Cow` with its lifetime and type arguments.
+///
+/// The path can't be resolved by the macro, so a plain `Cow` is assumed to be imported from `std` or `alloc`.
+fn is_std_cow(path: &syn::Path) -> bool {
+    let idents: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let is_cow_path = matches!(
+        idents.iter().map(String::as_str).collect::<Vec<_>>()[..],
+        ["Cow"] | ["borrow", "Cow"] | ["std" | "alloc", "borrow", "Cow"]
+    );
+
+    let has_cow_arguments = match path.segments.last().map(|segment| &segment.arguments) {
+        Some(syn::PathArguments::AngleBracketed(generic_args)) => matches!(
+            generic_args.args.iter().collect::<Vec<_>>()[..],
+            [
+                syn::GenericArgument::Lifetime(_),
+                syn::GenericArgument::Type(_)
+            ]
+        ),
+        _ => false,
+    };
+
+    is_cow_path && has_cow_arguments
+}
+
+fn static_type(mut ty: syn::Type) -> syn::Type {
+    rename_lifetimes(&mut ty, &mut |lifetime| match lifetime {
+        Some(lifetime) if lifetime.ident == "static" => None,
+        _ => Some("'static"),
+    });
+    add_dyn_static_bound(&mut ty);
+    ty
+}
+
+/// Expand e.g. `Self::Item<'a>` into the generic associated type given in the attribute,
+/// e.g. `type Item<'a> = &'a str;`, so that its borrows can be analyzed like any other output.
+/// Returns `None` when the output does not mention any such associated type.
+fn expand_generic_associated_type(output: &syn::ReturnType, attr: &Attr) -> Option<syn::Type> {
+    struct Expander<'a> {
+        attr: &'a Attr,
+        expanded: bool,
+    }
+
+    impl<'a> VisitMut for Expander<'a> {
+        fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+            if let Some(expanded_ty) = expand_self_path(ty, self.attr) {
+                *ty = expanded_ty;
+                self.expanded = true;
+            }
+
+            syn::visit_mut::visit_type_mut(self, ty);
+        }
+    }
+
+    let syn::ReturnType::Type(_, output_ty) = output else {
+        return None;
+    };
+    let mut output_ty = *output_ty.clone();
+    let mut expander = Expander {
+        attr,
+        expanded: false,
+    };
+    expander.visit_type_mut(&mut output_ty);
+
+    if expander.expanded {
+        Some(output_ty)
+    } else {
+        None
+    }
+}
+
+fn expand_self_path(ty: &syn::Type, attr: &Attr) -> Option<syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some()
+        || type_path.path.segments.len() != 2
+        || !is_self_segment(type_path.path.segments.first())
+    {
+        return None;
+    }
+
+    let assoc_segment = &type_path.path.segments[1];
+    let attr_assoc_type = attr
+        .associated_types
+        .get(&assoc_segment.ident.to_string())?;
+    if attr_assoc_type.generics.params.is_empty() {
+        return None;
+    }
+    let (_, assoc_ty) = attr_assoc_type.default.as_ref()?;
+
+    let args: Vec<&syn::GenericArgument> = match &assoc_segment.arguments {
+        syn::PathArguments::AngleBracketed(bracketed) => bracketed.args.iter().collect(),
+        _ => vec![],
+    };
+
+    let mut substitutions = GenericSubstitutions::default();
+    for (param, arg) in attr_assoc_type.generics.params.iter().zip(args) {
+        match (param, arg) {
+            (syn::GenericParam::Lifetime(param), syn::GenericArgument::Lifetime(arg)) => {
+                substitutions
+                    .lifetimes
+                    .push((param.lifetime.clone(), arg.clone()));
+            }
+            (syn::GenericParam::Type(param), syn::GenericArgument::Type(arg)) => {
+                substitutions.types.push((param.ident.clone(), arg.clone()));
+            }
+            _ => return None,
+        }
+    }
+
+    let mut expanded_ty = assoc_ty.clone();
+    substitutions.visit_type_mut(&mut expanded_ty);
+
+    Some(expanded_ty)
+}
+
+#[derive(Default)]
+struct GenericSubstitutions {
+    lifetimes: Vec<(syn::Lifetime, syn::Lifetime)>,
+    types: Vec<(syn::Ident, syn::Type)>,
+}
+
+impl VisitMut for GenericSubstitutions {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+        if let Some((_, arg)) = self.lifetimes.iter().find(|(param, _)| param == lifetime) {
+            *lifetime = arg.clone();
+        }
+    }
+
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(type_path) = ty {
+            if type_path.qself.is_none() {
+                if let Some(ident) = type_path.path.get_ident() {
+                    if let Some((_, arg)) = self.types.iter().find(|(param, _)| param == ident) {
+                        *ty = arg.clone();
+                        return;
+                    }
+                }
+            }
+        }
+
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
 fn is_self_segment(segment: Option<&syn::PathSegment>) -> bool {
     match segment {
         None => false,
@@ -254,7 +547,7 @@ fn determine_associated_future_structure(
     let future_bound = find_future_bound(assoc_ty.bounds.iter())?;
     let mut future_output_structure =
         determine_owned_or_mixed_output_structure(sig, &future_bound.output.ty, item_trait, attr);
-    future_output_structure.wrapping = OutputWrapping::AssociatedFuture(assoc_ty.clone());
+    future_output_structure.wrapping = OutputWrapping::AssociatedFuture(Box::new(assoc_ty.clone()));
 
     Some(future_output_structure)
 }
@@ -307,7 +600,7 @@ fn find_param_lifetime(sig: &syn::Signature, lifetime_ident: &syn::Ident) -> Opt
 #[derive(Clone)]
 enum AssociatedInnerType {
     Unit,
-    Typed(syn::Type),
+    Typed(Box<syn::Type>),
     SameAsResponse,
 }
 
@@ -318,7 +611,7 @@ impl AssociatedInnerType {
             add_dyn_static_bound(&mut inner_type);
         }
 
-        Self::Typed(inner_type)
+        Self::Typed(Box::new(inner_type))
     }
 
     fn new_gat(
@@ -351,7 +644,7 @@ impl AssociatedInnerType {
                     add_dyn_static_bound(&mut inner_type);
 
                     if needs_lifetime_gat {
-                        Self::Typed(inner_type)
+                        Self::Typed(Box::new(inner_type))
                     } else {
                         Self::SameAsResponse
                     }
@@ -383,6 +676,10 @@ struct BorrowInfo {
 }
 
 impl BorrowInfo {
+    fn borrows_from_self(&self) -> bool {
+        self.has_elided_lifetime || self.has_self_lifetime
+    }
+
     fn equals_self_lifetime(&self, lifetime: &syn::Lifetime) -> bool {
         match &self.self_lifetime_ident {
             Some(ident) => lifetime.ident == ident,
@@ -403,7 +700,7 @@ impl<'s> ReturnTypeAnalyzer<'s> {
     }
 
     fn analyze_lifetime(&mut self, lifetime: Option<&syn::Lifetime>, is_reference: bool) {
-        match lifetime {
+        match lifetime.filter(|lifetime| lifetime.ident != "_") {
             Some(lifetime) => match lifetime.ident.to_string().as_ref() {
                 "static" => {
                     self.borrow_info.has_static_lifetime = true;
@@ -515,13 +812,15 @@ fn add_dyn_static_bound(ty: &mut syn::Type) {
                         // already parenthesized
                     }
                     _ => {
-                        let mut tmp = Box::new(syn::Type::Verbatim(TokenStream::new()));
-                        std::mem::swap(&mut tmp, &mut ty.elem);
+                        let tmp = std::mem::replace(
+                            ty.elem.as_mut(),
+                            syn::Type::Verbatim(TokenStream::new()),
+                        );
 
-                        ty.elem = Box::new(syn::Type::Paren(syn::TypeParen {
+                        *ty.elem = syn::Type::Paren(syn::TypeParen {
                             paren_token: syn::token::Paren::default(),
-                            elem: tmp,
-                        }));
+                            elem: Box::new(tmp),
+                        });
                     }
                 }
             }
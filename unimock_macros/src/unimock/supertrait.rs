@@ -0,0 +1,81 @@
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use super::attr::{Attr, Supertrait};
+use super::trait_info::TraitInfo;
+use super::util::{Generics, InferImplTrait};
+
+/// Compile-time check that Unimock (or the wrapper) implements each listed supertrait.
+///
+/// The check is a helper trait with the supertrait as its own supertrait, implemented for Unimock.
+/// Compile errors are reported at the supertrait entry in the attribute.
+pub fn def_supertrait_assertions(trait_info: &TraitInfo, attr: &Attr) -> proc_macro2::TokenStream {
+    let trait_generics = &trait_info.input_trait.generics;
+    let where_clause = &trait_info.input_trait.generics.where_clause;
+    let generic_params = Generics::trait_params(trait_info, None);
+    let generic_args = Generics::trait_args(trait_info, None, InferImplTrait(false));
+
+    let assertions = attr
+        .supertraits
+        .iter()
+        .enumerate()
+        .map(|(index, Supertrait { path, .. })| {
+            let span = path.span();
+            let assert_ident = format_ident!("__UnimockSupertrait{}", index, span = span);
+            let self_type = attr.self_type();
+
+            quote_spanned! { span=>
+                #[allow(non_camel_case_types)]
+                trait #assert_ident #trait_generics: #path #where_clause {}
+
+                impl #generic_params #assert_ident #generic_args for #self_type #where_clause {}
+            }
+        });
+
+    quote! {
+        #(#assertions)*
+    }
+}
+
+/// Re-exports of existing supertrait mock APIs, to put inside the mock API module.
+pub fn def_supertrait_reexports(trait_info: &TraitInfo, attr: &Attr) -> proc_macro2::TokenStream {
+    // A private trait's mock API may only be re-exported to the module containing the trait:
+    let vis = match &trait_info.input_trait.vis {
+        syn::Visibility::Public(_) => quote! { pub },
+        syn::Visibility::Restricted(restricted) if restricted.path.is_ident("crate") => {
+            quote! { pub(crate) }
+        }
+        _ => quote! { pub(super) },
+    };
+
+    let reexports = attr.supertraits.iter().filter_map(|supertrait| {
+        let mock_api = supertrait.mock_api.as_ref()?;
+        let ident = &supertrait.path.segments.last()?.ident;
+        let mock_api = path_from_inner_module(mock_api);
+
+        Some(quote! {
+            #vis use #mock_api as #ident;
+        })
+    });
+
+    quote! {
+        #(#reexports)*
+    }
+}
+
+/// Make a path written outside the mock API module resolve from within it.
+fn path_from_inner_module(path: &syn::Path) -> syn::Path {
+    if path.leading_colon.is_some() {
+        return path.clone();
+    }
+
+    let mut path = path.clone();
+    match path.segments.first() {
+        Some(first) if first.ident == "crate" => path,
+        Some(first) if first.ident == "self" => {
+            path.segments[0].ident = format_ident!("super", span = first.ident.span());
+            path
+        }
+        _ => syn::parse_quote! { super::#path },
+    }
+}
@@ -1,7 +1,9 @@
 use super::attr::Attr;
 use super::method;
 use super::output::OutputWrapping;
-use super::util::{GenericParamsWithBounds, IsGeneric, IsTypeGeneric};
+use super::util::{
+    erase_lifetimes_in_where_clause, GenericParamsWithBounds, IsGeneric, IsTypeGeneric,
+};
 
 pub struct TraitInfo<'t> {
     pub input_trait: &'t syn::ItemTrait,
@@ -9,6 +11,7 @@ pub struct TraitInfo<'t> {
     pub trait_path: syn::Path,
     pub ident_lit: syn::LitStr,
     pub generic_params_with_bounds: GenericParamsWithBounds,
+    pub mock_fn_where_clause: Option<syn::WhereClause>,
     pub methods: Vec<Option<method::MockMethod<'t>>>,
     pub has_default_impls: bool,
     pub is_generic: IsGeneric,
@@ -19,13 +22,12 @@ impl<'t> TraitInfo<'t> {
     pub fn analyze(input_trait: &'t syn::ItemTrait, attr: &Attr) -> syn::Result<Self> {
         let generics = &input_trait.generics;
         let is_generic = IsGeneric(!input_trait.generics.params.is_empty());
-        let is_type_generic = IsTypeGeneric(
-            input_trait
-                .generics
-                .params
-                .iter()
-                .any(|param| matches!(param, syn::GenericParam::Type(_))),
-        );
+        let is_type_generic = IsTypeGeneric(input_trait.generics.params.iter().any(|param| {
+            matches!(
+                param,
+                syn::GenericParam::Type(_) | syn::GenericParam::Const(_)
+            )
+        }));
 
         let methods = method::extract_methods(input_trait, is_type_generic, attr)?;
 
@@ -45,7 +47,7 @@ impl<'t> TraitInfo<'t> {
         });
 
         let ident_lit =
-            syn::LitStr::new(&format!("{}", &input_trait.ident), input_trait.ident.span());
+            syn::LitStr::new(&format!("{}", input_trait.ident), input_trait.ident.span());
 
         let (output_trait, trait_path) = if let Some(mirror) = &attr.mirror {
             (None, mirror.clone())
@@ -54,12 +56,22 @@ impl<'t> TraitInfo<'t> {
             (Some(input_trait), syn::parse_quote! { #trait_ident })
         };
 
+        let trait_lifetimes = generics
+            .lifetimes()
+            .map(|param| param.lifetime.clone())
+            .collect::<Vec<_>>();
+        let mock_fn_where_clause = generics
+            .where_clause
+            .clone()
+            .map(|where_clause| erase_lifetimes_in_where_clause(where_clause, &trait_lifetimes));
+
         Ok(Self {
             input_trait,
             output_trait,
             trait_path,
             ident_lit,
             generic_params_with_bounds: GenericParamsWithBounds::new(generics, contains_async),
+            mock_fn_where_clause,
             methods,
             has_default_impls,
             is_generic,
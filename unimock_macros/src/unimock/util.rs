@@ -6,6 +6,7 @@ use syn::TypeParamBound;
 #[derive(Clone, Copy)]
 pub struct IsGeneric(pub bool);
 
+/// Whether there are type or const generic parameters, i.e. the MockFn needs `with_types`.
 #[derive(Clone, Copy)]
 pub struct IsTypeGeneric(pub bool);
 
@@ -21,6 +22,7 @@ enum GenericsKind {
     FnParams,
     TraitArgs(InferImplTrait),
     FnArgs(InferImplTrait),
+    FnStructParams,
 }
 
 #[derive(Clone, Copy)]
@@ -29,6 +31,9 @@ pub struct IncludeTraitLifetimes(pub bool);
 #[derive(Clone, Copy)]
 pub struct InferImplTrait(pub bool);
 
+#[derive(Clone, Copy)]
+pub struct DeclareConsts(pub bool);
+
 fn is_generic(trait_info: &TraitInfo, method: Option<&MockMethod<'_>>) -> IsGeneric {
     if trait_info.is_generic.0 {
         return IsGeneric(true);
@@ -117,32 +122,61 @@ impl<'t> Generics<'t> {
         }
     }
 
+    // Params of the generic MockFn struct: e.g. struct __GenericFoo<A, const N: usize>
+    pub fn fn_struct_params(trait_info: &'t TraitInfo, method: Option<&'t MockMethod<'t>>) -> Self {
+        Self {
+            trait_info,
+            method,
+            kind: if is_type_generic(trait_info, method).0 {
+                GenericsKind::FnStructParams
+            } else {
+                GenericsKind::None
+            },
+        }
+    }
+
     fn args_iterator(
         &self,
         include_trait_lifetimes: IncludeTraitLifetimes,
         infer_impl_trait: InferImplTrait,
+        declare_consts: DeclareConsts,
     ) -> impl Iterator<Item = proc_macro2::TokenStream> + '_ {
+        let const_arg = move |const_param: &syn::ConstParam| {
+            let ident = &const_param.ident;
+            if declare_consts.0 {
+                let ty = &const_param.ty;
+                quote! { const #ident: #ty }
+            } else {
+                quote! { #ident }
+            }
+        };
+
+        // A declared type parameter keeps its `?Sized` relaxation
+        let type_arg = move |type_param: &syn::TypeParam| {
+            let ident = &type_param.ident;
+            if declare_consts.0 && is_maybe_sized(type_param) {
+                quote! { #ident: ?Sized }
+            } else {
+                quote! { #ident }
+            }
+        };
+
         self.trait_info
             .input_trait
             .generics
             .params
             .iter()
-            .map(move |trait_param| match trait_param {
-                syn::GenericParam::Lifetime(lifetime) => {
+            .filter_map(move |trait_param| match trait_param {
+                syn::GenericParam::Lifetime(lifetime_param) => {
                     if include_trait_lifetimes.0 {
-                        quote! { #lifetime }
+                        let lifetime = &lifetime_param.lifetime;
+                        Some(quote! { #lifetime })
                     } else {
-                        quote! {}
+                        None
                     }
                 }
-                syn::GenericParam::Type(type_param) => {
-                    let ident = &type_param.ident;
-                    quote! { #ident }
-                }
-                syn::GenericParam::Const(const_param) => {
-                    let ident = &const_param.ident;
-                    quote! { #ident }
-                }
+                syn::GenericParam::Type(type_param) => Some(type_arg(type_param)),
+                syn::GenericParam::Const(const_param) => Some(const_arg(const_param)),
             })
             .chain(
                 self.method
@@ -164,18 +198,46 @@ impl<'t> Generics<'t> {
                             {
                                 Some(quote! { _ })
                             } else {
-                                Some(quote! { #ident })
+                                Some(type_arg(type_param))
                             }
                         }
-                        syn::GenericParam::Const(const_param) => {
-                            let ident = &const_param.ident;
-                            Some(quote! { #ident })
-                        }
+                        syn::GenericParam::Const(const_param) => Some(const_arg(const_param)),
                     }),
             )
     }
 }
 
+fn is_maybe_sized(type_param: &syn::TypeParam) -> bool {
+    type_param.bounds.iter().any(|bound| match bound {
+        TypeParamBound::Trait(trait_bound) => {
+            matches!(trait_bound.modifier, syn::TraitBoundModifier::Maybe(_))
+                && trait_bound.path.is_ident("Sized")
+        }
+        _ => false,
+    })
+}
+
+/// The trait's generic params with bounds, including its lifetimes.
+pub fn trait_param_list(trait_info: &TraitInfo) -> Vec<proc_macro2::TokenStream> {
+    let lifetimes =
+        trait_info.input_trait.generics.params.iter().filter_map(
+            |generic_param| match generic_param {
+                syn::GenericParam::Lifetime(lt) => Some(quote! { #lt }),
+                _ => None,
+            },
+        );
+
+    lifetimes
+        .chain(
+            trait_info
+                .generic_params_with_bounds
+                .params
+                .iter()
+                .map(|param| quote! { #param }),
+        )
+        .collect()
+}
+
 impl<'t> quote::ToTokens for Generics<'t> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         if let GenericsKind::None = &self.kind {
@@ -186,40 +248,64 @@ impl<'t> quote::ToTokens for Generics<'t> {
         match &self.kind {
             GenericsKind::None => {}
             GenericsKind::TraitParams | GenericsKind::FnParams => {
-                if let GenericsKind::TraitParams = &self.kind {
-                    for generic_param in &self.trait_info.input_trait.generics.params {
-                        if let syn::GenericParam::Lifetime(lt) = generic_param {
-                            lt.to_tokens(tokens);
-                        }
-                    }
-                }
-
-                self.trait_info
-                    .generic_params_with_bounds
-                    .params
-                    .to_tokens(tokens);
+                let mut params = match &self.kind {
+                    GenericsKind::TraitParams => trait_param_list(self.trait_info),
+                    _ => self
+                        .trait_info
+                        .generic_params_with_bounds
+                        .params
+                        .iter()
+                        .map(|param| quote! { #param })
+                        .collect(),
+                };
                 if let Some(method) = self.method {
-                    if !self.trait_info.generic_params_with_bounds.params.is_empty() {
-                        quote! { , }.to_tokens(tokens);
-                    }
+                    params.extend(
+                        method
+                            .generic_params_with_bounds
+                            .params
+                            .iter()
+                            .map(|param| quote! { #param }),
+                    );
+                }
 
-                    method.generic_params_with_bounds.params.to_tokens(tokens);
+                quote! {
+                    #(#params),*
                 }
+                .to_tokens(tokens);
             }
             GenericsKind::TraitArgs(infer_impl_trait) => {
-                let args = self.args_iterator(IncludeTraitLifetimes(true), *infer_impl_trait);
+                let args = self.args_iterator(
+                    IncludeTraitLifetimes(true),
+                    *infer_impl_trait,
+                    DeclareConsts(false),
+                );
                 quote! {
                     #(#args),*
                 }
                 .to_tokens(tokens);
             }
             GenericsKind::FnArgs(infer_impl_trait) => {
-                let args = self.args_iterator(IncludeTraitLifetimes(false), *infer_impl_trait);
+                let args = self.args_iterator(
+                    IncludeTraitLifetimes(false),
+                    *infer_impl_trait,
+                    DeclareConsts(false),
+                );
                 quote! {
                     #(#args),*
                 }
                 .to_tokens(tokens);
             }
+            GenericsKind::FnStructParams => {
+                let params = self.args_iterator(
+                    IncludeTraitLifetimes(false),
+                    InferImplTrait(false),
+                    DeclareConsts(true),
+                );
+                quote! {
+                    #(#params),*
+                }
+                .to_tokens(tokens);
+            }
         }
         syn::token::Gt::default().to_tokens(tokens);
     }
@@ -233,16 +319,14 @@ pub struct MockFnPhantomsTuple<'t> {
 impl<'t> quote::ToTokens for MockFnPhantomsTuple<'t> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         if self.trait_info.is_type_generic.0 || self.method.is_type_generic.0 {
-            let phantom_data = iter_generic_type_params(self.trait_info, self.method)
-                .map(TypedPhantomData)
-                .collect::<Vec<_>>();
+            // Const-only generics produce an empty tuple struct
+            let phantom_data =
+                iter_generic_type_params(self.trait_info, self.method).map(TypedPhantomData);
 
-            if !phantom_data.is_empty() {
-                quote! {
-                    (#(#phantom_data),*)
-                }
-                .to_tokens(tokens);
+            quote! {
+                (#(#phantom_data),*)
             }
+            .to_tokens(tokens);
         }
     }
 }
@@ -294,6 +378,33 @@ pub fn substitute_lifetimes(mut ty: syn::Type, lifetime: &syn::Lifetime) -> syn:
     ty
 }
 
+/// Replace the given lifetimes with `'static` in a where clause.
+///
+/// The MockFn of a lifetime-generic trait does not have the trait's lifetime parameters,
+/// so predicates that mention them have to be expressed in terms of `'static`.
+pub fn erase_lifetimes_in_where_clause(
+    mut where_clause: syn::WhereClause,
+    lifetimes: &[syn::Lifetime],
+) -> syn::WhereClause {
+    struct LifetimeEraser<'s> {
+        lifetimes: &'s [syn::Lifetime],
+    }
+
+    impl<'s> syn::visit_mut::VisitMut for LifetimeEraser<'s> {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+            if self.lifetimes.contains(lifetime) {
+                *lifetime = syn::Lifetime::new("'static", lifetime.span());
+            }
+        }
+    }
+
+    let mut eraser = LifetimeEraser { lifetimes };
+    use syn::visit_mut::VisitMut;
+    eraser.visit_where_clause_mut(&mut where_clause);
+
+    where_clause
+}
+
 pub fn self_type_to_unimock(
     mut ty: syn::Type,
     item_trait: &syn::ItemTrait,
@@ -307,16 +418,20 @@ pub fn self_type_to_unimock(
     impl<'a> syn::visit_mut::VisitMut for SelfTypeToUnimock<'a> {
         fn visit_type_path_mut(&mut self, node: &mut syn::TypePath) {
             if node.path.is_ident("Self") {
-                let prefix = &self.attr.prefix;
-                node.path = syn::parse_quote!(#prefix::Unimock);
+                node.path = self.attr.self_type();
             } else if node.path.segments.len() == 2 {
                 let first_segment = &node.path.segments[0];
                 if first_segment.ident == "Self" {
-                    let prefix = &self.attr.prefix;
+                    let self_type = self.attr.self_type();
                     let trait_ident = &self.item_trait.ident;
+                    let trait_path: syn::Path = match &self.attr.mirror {
+                        Some(mirror) => mirror.clone(),
+                        None => syn::parse_quote!(#trait_ident),
+                    };
+                    let (_, trait_generic_args, _) = self.item_trait.generics.split_for_impl();
                     let second_segment = &node.path.segments[1];
 
-                    *node = syn::parse_quote!(<#prefix::Unimock as #trait_ident>::#second_segment);
+                    *node = syn::parse_quote!(<#self_type as #trait_path #trait_generic_args>::#second_segment);
                 }
             }
 
@@ -358,12 +473,12 @@ pub fn iter_generic_type_params<'t>(
 }
 
 pub struct GenericParamsWithBounds {
-    pub params: syn::punctuated::Punctuated<syn::TypeParam, syn::token::Comma>,
+    pub params: syn::punctuated::Punctuated<syn::GenericParam, syn::token::Comma>,
 }
 
 impl GenericParamsWithBounds {
     pub fn new(generics: &syn::Generics, contains_async: bool) -> Self {
-        let mut params: syn::punctuated::Punctuated<syn::TypeParam, syn::token::Comma> =
+        let mut params: syn::punctuated::Punctuated<syn::GenericParam, syn::token::Comma> =
             Default::default();
 
         // add 'static bounds
@@ -372,12 +487,30 @@ impl GenericParamsWithBounds {
             if let syn::GenericParam::Type(type_param) = generic_param {
                 let mut bounded_param = type_param.clone();
 
+                // Lifetime bounds are implied by `'static`, and would otherwise refer
+                // to lifetimes that are not in scope where the MockFn is implemented:
+                bounded_param.bounds = bounded_param
+                    .bounds
+                    .into_iter()
+                    .filter(|bound| match bound {
+                        syn::TypeParamBound::Lifetime(lifetime) => lifetime.ident == "static",
+                        _ => true,
+                    })
+                    .collect();
+
                 add_static_bound_if_not_present(&mut bounded_param);
                 if contains_async {
                     add_send_bound_if_not_present(&mut bounded_param);
                 }
 
-                params.push(bounded_param);
+                params.push(syn::GenericParam::Type(bounded_param));
+            } else if let syn::GenericParam::Const(const_param) = generic_param {
+                let mut const_param = const_param.clone();
+                // defaults are not allowed in impl generics
+                const_param.eq_token = None;
+                const_param.default = None;
+
+                params.push(syn::GenericParam::Const(const_param));
             }
         }
 
@@ -439,7 +572,6 @@ pub fn replace_self_ty_with_path(mut ty: syn::Type, replacement_path: &syn::Path
 }
 
 pub struct FutureBound<'s> {
-    pub future_ident: &'s syn::Ident,
     pub output: &'s syn::AssocType,
 }
 
@@ -471,7 +603,6 @@ pub fn find_future_bound<'s>(
             .next()?;
 
         Some(FutureBound {
-            future_ident: &last_segment.ident,
             output: output_assoc,
         })
     }
@@ -482,3 +613,8 @@ pub fn find_future_bound<'s>(
 pub struct RpitFuture {
     pub output: syn::AssocType,
 }
+
+/// A non-future `impl Trait` in return position, which gets boxed behind a local newtype.
+pub struct RpitBoxed {
+    pub bounds: syn::punctuated::Punctuated<TypeParamBound, syn::token::Plus>,
+}